@@ -0,0 +1,21 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use advent_of_code_2020::day15::count;
+
+fn play_game(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count::play_game");
+
+    // A full 30 million round game takes seconds, so take as few samples as criterion allows
+    group.sample_size(10);
+
+    for rounds in [2_020, 300_000, 30_000_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(rounds), &rounds, |b, rounds| {
+            b.iter(|| count::play_game(&[6, 3, 15, 13, 1, 0], *rounds))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, play_game);
+criterion_main!(benches);
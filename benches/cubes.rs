@@ -0,0 +1,43 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use advent_of_code_2020::day17::cubes::PocketDimension;
+
+mod common;
+
+fn initial_state(size: usize) -> PocketDimension {
+    let mut lcg = common::Lcg::new(17);
+    let mut grid = String::with_capacity((size + 1) * size);
+
+    for _ in 0..size {
+        for _ in 0..size {
+            grid.push(if lcg.next_below(2) == 0 { '#' } else { '.' });
+        }
+
+        grid.push('\n');
+    }
+
+    PocketDimension::from(grid)
+}
+
+fn into_next_round_hypercube(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PocketDimension::into_next_round_hypercube");
+    group.sample_size(10);
+
+    // The active region grows every round, so later rounds are much more expensive than early ones
+    let mut pocket_dimension = initial_state(8);
+
+    for round in 1..=6 {
+        group.bench_with_input(BenchmarkId::new("round", round), &pocket_dimension, |b, pocket_dimension| {
+            b.iter_batched(|| pocket_dimension.clone(),
+                           |pocket_dimension| pocket_dimension.into_next_round_hypercube(),
+                           BatchSize::LargeInput)
+        });
+
+        pocket_dimension = pocket_dimension.into_next_round_hypercube();
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, into_next_round_hypercube);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ksum::{KSumStrategy, SortedSlice};
+
+mod common;
+
+fn numbers(n: usize) -> Vec<i64> {
+    let mut lcg = common::Lcg::new(2020);
+    (0..n).map(|_| lcg.next_below(2_000_001) as i64 - 1_000_000).collect()
+}
+
+fn sorted_ksum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ksum");
+
+    for (n, k) in [(100_000, 2), (1_000, 3), (200, 4)] {
+        let mut numbers = numbers(n);
+        let numbers = SortedSlice::sort(&mut numbers);
+
+        group.bench_with_input(BenchmarkId::new(format!("k={}", k), n), &numbers, |b, numbers| {
+            b.iter(|| ksum::ksum(*numbers, k, 0).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ksum_with_strategy");
+    let numbers = numbers(200);
+
+    for strategy in [KSumStrategy::TwoPointer, KSumStrategy::Hash, KSumStrategy::MeetInTheMiddle] {
+        group.bench_with_input(BenchmarkId::new(format!("{:?}", strategy), "k=4, n=200"), &strategy, |b, strategy| {
+            b.iter(|| ksum::ksum_with_strategy(&numbers, 4, 0, *strategy).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, sorted_ksum, strategies);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use advent_of_code_2020::day19::message::RuleSet;
+
+mod common;
+
+// The puzzle's example rules, with the looping replacements for rules 8 and 11 from part 2
+const RULES: &str = "42: 9 14 | 10 1\n9: 14 27 | 1 26\n10: 23 14 | 28 1\n1: \"a\"\n11: 42 31 | 42 11 31\n5: 1 14 | 15 1\n\
+    19: 14 1 | 14 14\n12: 24 14 | 19 1\n16: 15 1 | 14 14\n31: 14 17 | 1 13\n6: 14 14 | 1 14\n2: 1 24 | 14 4\n0: 8 11\n\
+    13: 14 3 | 1 12\n15: 1 | 14\n17: 14 2 | 1 7\n23: 25 1 | 22 14\n28: 16 1\n4: 1 1\n20: 14 14 | 1 15\n3: 5 14 | 16 1\n\
+    27: 1 6 | 14 18\n14: \"b\"\n21: 14 1 | 1 14\n25: 1 1 | 1 14\n22: 14 14\n8: 42 | 42 8\n26: 14 22 | 1 20\n18: 15 15\n\
+    7: 14 5 | 1 21\n24: 14 1\n";
+
+// Rule 42 and rule 31 each match 5 characters, so matching messages are made of 5-character chunks
+// matched by rule 42 followed by fewer chunks matched by rule 31
+const CHUNKS_42: [&str; 4] = ["bbabb", "babbb", "aaaab", "bbaaa"];
+const CHUNKS_31: [&str; 4] = ["bbaba", "aabaa", "babab", "ababb"];
+
+fn messages(count: usize) -> Vec<String> {
+    let mut lcg = common::Lcg::new(19);
+
+    (0..count)
+        .map(|_| {
+            let matched = lcg.next_below(4) as usize + 1;
+            let repeated = matched + lcg.next_below(4) as usize + 1;
+
+            let mut message: String = (0..repeated).map(|_| CHUNKS_42[lcg.next_below(4) as usize]).collect();
+            message.extend((0..matched).map(|_| CHUNKS_31[lcg.next_below(4) as usize]));
+
+            // Flip a character in about half of the messages so not everything matches
+            if lcg.next_below(2) == 0 {
+                let i = lcg.next_below(message.len() as u64) as usize;
+                let flipped = if &message[i..=i] == "a" { "b" } else { "a" };
+                message.replace_range(i..=i, flipped);
+            }
+
+            message
+        })
+        .collect()
+}
+
+fn matches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("RuleSet::matches");
+    let rules: RuleSet = RULES.parse().unwrap();
+
+    for count in [100, 1_000] {
+        let messages = messages(count);
+        let max_length = messages.iter().map(String::len).max().unwrap_or(0);
+
+        group.bench_with_input(BenchmarkId::new("backtracking", count), &messages, |b, messages| {
+            b.iter(|| messages.iter().filter(|message| rules.matches(0, message)).count())
+        });
+
+        // Compiling is part of the cost of matching a batch of messages, so it's measured too
+        group.bench_with_input(BenchmarkId::new("compiled", count), &messages, |b, messages| {
+            b.iter(|| {
+                let compiled = rules.compile(0, max_length).unwrap();
+                messages.iter().filter(|message| compiled.matches(message)).count()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, matches);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use advent_of_code_2020::day11::seating::SeatingMap;
+
+// Random layouts can oscillate forever instead of settling, so tile the puzzle's example layout,
+// which is known to settle, to get a map of the size we want
+const EXAMPLE_LAYOUT: [&str; 10] = [
+    "L.LL.LL.LL",
+    "LLLLLLL.LL",
+    "L.L.L..L..",
+    "LLLL.LL.LL",
+    "L.LL.LL.LL",
+    "L.LLLLL.LL",
+    "..L.L.....",
+    "LLLLLLLLLL",
+    "L.LLLLLL.L",
+    "L.LLLLL.LL",
+];
+
+fn layout(tiles: usize) -> String {
+    let mut layout = String::new();
+
+    for _ in 0..tiles {
+        for row in EXAMPLE_LAYOUT.iter() {
+            layout.push_str(&row.repeat(tiles));
+            layout.push('\n');
+        }
+    }
+
+    layout
+}
+
+fn into_stable_configuration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SeatingMap::into_stable_configuration");
+    group.sample_size(20);
+
+    for tiles in [5, 10] {
+        let layout = layout(tiles);
+        let size = tiles * EXAMPLE_LAYOUT.len();
+
+        group.bench_with_input(BenchmarkId::new("adjacency", size), &layout, |b, layout| {
+            b.iter_batched(|| SeatingMap::from_layout(layout.clone()),
+                           |map| map.into_stable_configuration_by_adjacency(),
+                           BatchSize::SmallInput)
+        });
+
+        group.bench_with_input(BenchmarkId::new("visibility", size), &layout, |b, layout| {
+            b.iter_batched(|| SeatingMap::from_layout(layout.clone()),
+                           |map| map.into_stable_configuration_by_visibility(),
+                           BatchSize::SmallInput)
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, into_stable_configuration);
+criterion_main!(benches);
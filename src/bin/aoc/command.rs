@@ -0,0 +1,111 @@
+use std::error;
+
+use advent_of_code_2020::runner::{Format, Input};
+
+pub const USAGE: &str = "Usage: aoc run (DAY | all) [--format (text | json)] [--time] [--input (INPUT_FILE_PATH | -) | --input-string INPUT]";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Run { day: u8, input: Option<Input>, format: Format, time: bool },
+    RunAll { format: Format, time: bool },
+}
+
+impl Command {
+    pub fn from_args(args: &[String]) -> Result<Command, Box<dyn error::Error>> {
+        let mut args = args.iter();
+
+        if args.next().map(String::as_str) != Some("run") {
+            simple_error::bail!(USAGE);
+        }
+
+        let day = args.next().ok_or(USAGE)?;
+        let mut input = None;
+        let mut format = Format::Text;
+        let mut time = false;
+
+        while let Some(arg) = args.next() {
+            let source = match arg.as_str() {
+                "--time" => {
+                    time = true;
+                    continue;
+                },
+                "--format" => {
+                    format = Format::from_arg(args.next().ok_or("--format needs a value")?)?;
+                    continue;
+                },
+                "--input" => Input::from_path_arg(args.next().ok_or("--input needs a value")?),
+                "--input-string" => Input::from_input_string(args.next().ok_or("--input-string needs a value")?),
+                _ => simple_error::bail!("Unexpected argument: {}\n{}", arg, USAGE),
+            };
+
+            if input.replace(source).is_some() {
+                simple_error::bail!("Only one of --input and --input-string can be given");
+            }
+        }
+
+        if day == "all" {
+            if input.is_some() {
+                simple_error::bail!("--input and --input-string can only be used when running a single day");
+            }
+
+            Ok(Command::RunAll { format, time })
+        } else {
+            let day = day.parse().map_err(|_| format!("Invalid day: {}", day))?;
+
+            Ok(Command::Run { day, input, format, time })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use advent_of_code_2020::runner::{Format, Input};
+    use crate::command::Command;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn from_args() {
+        assert_eq!(Command::Run { day: 7, input: None, format: Format::Text, time: false }, Command::from_args(&args(&["run", "7"])).unwrap());
+
+        assert_eq!(Command::Run { day: 7, input: Some(Input::File(PathBuf::from("data/day07-input.txt"))), format: Format::Text, time: false },
+                   Command::from_args(&args(&["run", "7", "--input", "data/day07-input.txt"])).unwrap());
+
+        assert_eq!(Command::Run { day: 7, input: Some(Input::Stdin), format: Format::Text, time: false },
+                   Command::from_args(&args(&["run", "7", "--input", "-"])).unwrap());
+
+        assert_eq!(Command::Run { day: 15, input: Some(Input::Inline(String::from("0,3,6"))), format: Format::Text, time: false },
+                   Command::from_args(&args(&["run", "15", "--input-string", "0,3,6"])).unwrap());
+
+        assert_eq!(Command::RunAll { format: Format::Text, time: false }, Command::from_args(&args(&["run", "all"])).unwrap());
+
+        assert_eq!(Command::RunAll { format: Format::Json, time: false }, Command::from_args(&args(&["run", "all", "--format", "json"])).unwrap());
+
+        assert_eq!(Command::Run { day: 7, input: None, format: Format::Json, time: false },
+                   Command::from_args(&args(&["run", "7", "--format", "json"])).unwrap());
+
+        assert_eq!(Command::Run { day: 15, input: None, format: Format::Text, time: true },
+                   Command::from_args(&args(&["run", "15", "--time"])).unwrap());
+
+        assert_eq!(Command::RunAll { format: Format::Text, time: true }, Command::from_args(&args(&["run", "all", "--time"])).unwrap());
+    }
+
+    #[test]
+    fn from_args_errors() {
+        assert!(Command::from_args(&args(&[])).is_err());
+        assert!(Command::from_args(&args(&["walk", "7"])).is_err());
+        assert!(Command::from_args(&args(&["run"])).is_err());
+        assert!(Command::from_args(&args(&["run", "seven"])).is_err());
+        assert!(Command::from_args(&args(&["run", "7", "--input"])).is_err());
+        assert!(Command::from_args(&args(&["run", "7", "--verbose"])).is_err());
+        assert!(Command::from_args(&args(&["run", "7", "--format"])).is_err());
+        assert!(Command::from_args(&args(&["run", "7", "--format", "yaml"])).is_err());
+        assert!(Command::from_args(&args(&["run", "all", "--input", "data/day07-input.txt"])).is_err());
+        assert!(Command::from_args(&args(&["run", "all", "--input-string", "0,3,6"])).is_err());
+        assert!(Command::from_args(&args(&["run", "15", "--input", "-", "--input-string", "0,3,6"])).is_err());
+    }
+}
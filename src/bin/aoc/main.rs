@@ -0,0 +1,60 @@
+use std::{env, error};
+
+use advent_of_code_2020::profile::CountingAllocator;
+use advent_of_code_2020::runner::{self, Format, Input};
+use crate::command::Command;
+
+mod command;
+
+// Needed for the peak allocation numbers that come with --time
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() -> Result<(), Box<dyn error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match Command::from_args(&args)? {
+        Command::Run { day, input, format, time } => {
+            let input = input.unwrap_or_else(|| Input::File(runner::default_input_path(day)));
+            runner::print_solved(&runner::solve(day, &input)?, format, time);
+        },
+
+        Command::RunAll { format, time } => {
+            let mut solved = Vec::new();
+            let mut failures = 0;
+
+            // Keep going after a failure so one bad day doesn't hide the results for the rest
+            for (day, _) in advent_of_code_2020::puzzles() {
+                let input_path = runner::default_input_path(day);
+
+                // Not every solved day has its puzzle input checked in
+                if !input_path.exists() {
+                    eprintln!("Day {} skipped: no input at {}", day, input_path.display());
+                    continue;
+                }
+
+                match runner::solve(day, &Input::File(input_path)) {
+                    Ok(result) => {
+                        // The summary table covers per-step numbers for text output
+                        runner::print_solved(&result, format, time && format == Format::Json);
+                        solved.push(result);
+                    },
+                    Err(err) => {
+                        eprintln!("Day {} failed: {}", day, err);
+                        failures += 1;
+                    },
+                }
+            }
+
+            if time && format == Format::Text {
+                print!("\n{}", runner::format_summary(&solved));
+            }
+
+            if failures > 0 {
+                simple_error::bail!("{} day(s) failed", failures);
+            }
+        },
+    }
+
+    Ok(())
+}
@@ -1,53 +1,31 @@
-mod password;
+use std::{env, error};
 
-use std::{error, env, io};
-use std::io::BufRead;
-use std::fs::File;
+use advent_of_code_2020::day02::audit::{Interpretation, Options, ReportFormat};
+use advent_of_code_2020::day02::stream;
+use advent_of_code_2020::runner;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
-
-    if let Some(path) = args.get(1) {
-        {
-            let file = File::open(path)?;
-
-            let valid_passwords = io::BufReader::new(file).lines()
-                .filter_map(|line| { line.ok() })
-                .filter_map(|line| {
-                    let (policy, password) = password::parse(line.as_str()).unwrap();
-
-                    if password::is_valid_part1(policy, password) {
-                        Some(())
-                    } else {
-                        None
-                    }
-                })
-                .count();
-
-            println!("Valid passwords by part 1 policy: {}", valid_passwords);
-        }
-
-        {
-            let file = File::open(path)?;
-
-            let valid_passwords = io::BufReader::new(file).lines()
-                .filter_map(|line| { line.ok() })
-                .filter_map(|line| {
-                    let (policy, password) = password::parse(line.as_str()).unwrap();
-
-                    if password::is_valid_part2(policy, password) {
-                        Some(())
-                    } else {
-                        None
-                    }
-                })
-                .count();
-
-            println!("Valid passwords by part 2 policy: {}", valid_passwords);
-        }
-    } else {
-        simple_error::bail!("Usage: day02 INPUT_FILE_PATH");
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = Options::from_args(&args)?;
+
+    match options.audit {
+        Some(interpretation) => print!("{}", interpretation.audit(options.input.open()?)?.format(options.format)),
+
+        // JSON output includes timings, so it goes through the runner; plain answers can be counted
+        // in a single pass without holding the whole database in memory
+        None if options.format == ReportFormat::Json => {
+            runner::print_solved(&runner::solve(2, &options.input)?, options.answer_format(), false);
+        },
+        None => {
+            let counts = stream::count_valid(options.input.open()?, &[
+                &|policy| Interpretation::Count.policy(policy),
+                &|policy| Interpretation::Positions.policy(policy),
+            ])?;
+
+            println!("Day 2, part 1: {}", counts[0]);
+            println!("Day 2, part 2: {}", counts[1]);
+        },
     }
 
     Ok(())
-}
\ No newline at end of file
+}
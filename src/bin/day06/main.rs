@@ -1,34 +1,5 @@
-#[macro_use]
-extern crate lazy_static;
-
-use std::{error, env};
-
-mod customs;
+use std::error;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
-
-    if let Some(path) = args.get(1) {
-        {
-            let sum: usize = std::fs::read_to_string(path)?.split("\n\n")
-                .map(|answers| customs::FormGroup::from_answers(answers))
-                .map(|form_group| form_group.get_distinct_answers().len())
-                .sum();
-
-            println!("Union cardinality sum: {}", sum);
-        }
-
-        {
-            let sum: usize = std::fs::read_to_string(path)?.split("\n\n")
-                .map(|answers| customs::FormGroup::from_answers(answers))
-                .map(|form_group| form_group.get_universal_answers().len())
-                .sum();
-
-            println!("Intersection cardinality sum: {}", sum);
-        }
-    } else {
-        simple_error::bail!("Usage: day04 INPUT_FILE_PATH");
-    }
-
-    Ok(())
-}
\ No newline at end of file
+    advent_of_code_2020::runner::run_day(6)
+}
@@ -1,24 +1,5 @@
-use std::{error, env, io};
-use std::fs::File;
-use std::io::BufRead;
-use crate::bags::BagRules;
-
-mod bags;
+use std::error;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
-
-    if let Some(path) = args.get(1) {
-        let file = File::open(path)?;
-
-        let lines = io::BufReader::new(file).lines().map(|line| line.unwrap());
-        let rules: BagRules = lines.collect();
-
-        println!("Potential containers for shiny gold bags: {}", rules.get_top_level_containers(&String::from("shiny gold")));
-        println!("Shiny gold bags contain {} other bags", rules.get_total_contained_bags(&String::from("shiny gold")));
-    } else {
-        simple_error::bail!("Usage: day07 INPUT_FILE_PATH");
-    }
-
-    Ok(())
-}
\ No newline at end of file
+    advent_of_code_2020::runner::run_day(7)
+}
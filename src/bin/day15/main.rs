@@ -1,10 +1,5 @@
-mod count;
+use std::error;
 
-fn main() {
-    let initial_values = vec![6, 3, 15, 13, 1, 0];
-    println!("Round 2020 with initial values {:?}: {}",
-             initial_values, count::play_game(&initial_values, 2020));
-
-    println!("Round 30000000 with initial values {:?}: {}",
-             initial_values, count::play_game(&initial_values, 30000000));
-}
\ No newline at end of file
+fn main() -> Result<(), Box<dyn error::Error>> {
+    advent_of_code_2020::runner::run_day(15)
+}
@@ -1,3 +1,5 @@
-mod message;
+use std::error;
 
-fn main() {}
+fn main() -> Result<(), Box<dyn error::Error>> {
+    advent_of_code_2020::runner::run_day(19)
+}
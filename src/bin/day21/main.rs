@@ -0,0 +1,5 @@
+use std::error;
+
+fn main() -> Result<(), Box<dyn error::Error>> {
+    advent_of_code_2020::runner::run_day(21)
+}
@@ -0,0 +1,44 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+
+pub mod report;
+
+pub struct Day01;
+
+impl Day01 {
+    /// The expense report's entries, in the order they appear.
+    pub fn parse_entries(input: &str) -> Result<Vec<i64>, Box<dyn error::Error>> {
+        Ok(input.lines()
+            .map(|line| line.parse())
+            .collect::<Result<Vec<i64>, _>>()?)
+    }
+
+    fn find_product(entries: &[i64], k: usize) -> Result<Answer, Box<dyn error::Error>> {
+        let entries = ksum::SortedSlice::new(entries)?;
+
+        match ksum::KSumIter::new(entries, k, 2020)?.next() {
+            Some(solution) => Ok(Answer::from(solution.iter().product::<i64>())),
+            None => simple_error::bail!("No {} entries add up to 2020", k),
+        }
+    }
+}
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let mut entries = Day01::parse_entries(input)?;
+        entries.sort_unstable();
+
+        Ok(entries)
+    }
+
+    fn part1(&self, entries: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Day01::find_product(entries, 2)
+    }
+
+    fn part2(&self, entries: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Day01::find_product(entries, 3)
+    }
+}
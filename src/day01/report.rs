@@ -0,0 +1,196 @@
+use std::error;
+
+use crate::runner::{Format, Input};
+
+pub const USAGE: &str = "Usage: day01 [--target TARGET] [--k K[,K...]] [--all] [--format (text | json)] (INPUT_FILE_PATH | - | --input-string INPUT)";
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Options {
+    pub input: Input,
+    pub target: i64,
+    pub ks: Vec<usize>,
+    pub all: bool,
+    pub format: Format,
+}
+
+impl Options {
+    pub fn from_args(args: &[String]) -> Result<Options, Box<dyn error::Error>> {
+        let mut input = None;
+        let mut target = 2020;
+        let mut ks = vec![2, 3];
+        let mut all = false;
+        let mut format = Format::Text;
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--target" => {
+                    let value = args.next().ok_or("--target needs a value")?;
+                    target = value.parse().map_err(|_| format!("Invalid target: {}", value))?;
+                },
+                "--k" => {
+                    let value = args.next().ok_or("--k needs a value")?;
+
+                    ks = value.split(',')
+                        .map(|k| k.trim().parse().map_err(|_| format!("Invalid k: {}", k)))
+                        .collect::<Result<_, _>>()?;
+                },
+                "--all" => all = true,
+                "--format" => format = Format::from_arg(args.next().ok_or("--format needs a value")?)?,
+                "--input-string" => {
+                    let value = args.next().ok_or("--input-string needs a value")?;
+
+                    if input.replace(Input::from_input_string(value)).is_some() {
+                        simple_error::bail!("Only one input can be given");
+                    }
+                },
+                _ if arg.starts_with("--") => simple_error::bail!("Unrecognized option: {}", arg),
+                _ if input.is_none() => input = Some(Input::from_path_arg(arg)),
+                _ => simple_error::bail!("Unexpected argument: {}", arg),
+            }
+        }
+
+        // JSON output is just the puzzle's two answers, so there's no report to customize
+        if format == Format::Json && (target != 2020 || ks != [2, 3] || all) {
+            simple_error::bail!("--target, --k and --all can't be used with --format json");
+        }
+
+        match input {
+            Some(input) => Ok(Options { input, target, ks, all, format }),
+            None => simple_error::bail!(USAGE),
+        }
+    }
+}
+
+/// Prints the product of the first solution (or every solution, with `--all`) for each requested k,
+/// along with the lines its entries are on, or the closest sum when there isn't one. Entries are
+/// given in the order they appear in the input; equal entries on different lines make different
+/// solutions.
+pub fn print_report(entries: &[i64], options: &Options) -> Result<(), Box<dyn error::Error>> {
+    let mut sorted = entries.to_vec();
+    let sorted = ksum::SortedSlice::sort(&mut sorted);
+
+    for k in &options.ks {
+        let solutions = ksum::ksum_indices(entries, *k, options.target)?;
+
+        for indices in solutions.iter().take(if options.all { solutions.len() } else { 1 }) {
+            let numbers: Vec<i64> = indices.iter().map(|i| entries[*i]).collect();
+            println!("{} ({})", format_product(&numbers)?, describe_lines(indices));
+        }
+
+        if solutions.is_empty() {
+            let closest = ksum::closest_ksum(sorted, *k, options.target)?;
+            println!("No {} with sum {} found in input list; closest is {}",
+                     describe_k(*k), options.target, format_sum(&closest));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn describe_k(k: usize) -> String {
+    match k {
+        1 => String::from("single number"),
+        2 => String::from("pair"),
+        3 => String::from("triple"),
+        _ => format!("set of {} numbers", k),
+    }
+}
+
+/// Describes where entries came from, given their (zero-based) indices in the input.
+pub fn describe_lines(indices: &[usize]) -> String {
+    let lines: Vec<String> = indices.iter().map(|i| (i + 1).to_string()).collect();
+
+    match lines.split_last() {
+        Some((last, [])) => format!("line {}", last),
+        Some((last, rest)) => format!("lines {} and {}", rest.join(", "), last),
+        None => String::from("no lines"),
+    }
+}
+
+pub fn format_product(numbers: &[i64]) -> Result<String, Box<dyn error::Error>> {
+    let product = numbers.iter()
+        .try_fold(1i64, |product, n| product.checked_mul(*n))
+        .ok_or_else(|| format!("Product of {:?} overflows", numbers))?;
+
+    Ok(format!("{} = {}", join(numbers, " * "), product))
+}
+
+pub fn format_sum(numbers: &[i64]) -> String {
+    format!("{} = {}", join(numbers, " + "), numbers.iter().sum::<i64>())
+}
+
+fn join(numbers: &[i64], separator: &str) -> String {
+    numbers.iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day01::report;
+    use crate::day01::report::Options;
+    use crate::runner::{Format, Input};
+    use std::path::PathBuf;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn options_from_args() {
+        assert_eq!(Options { input: Input::File(PathBuf::from("input.txt")), target: 2020, ks: vec![2, 3], all: false, format: Format::Text },
+                   Options::from_args(&args(&["input.txt"])).unwrap());
+
+        assert_eq!(Options { input: Input::File(PathBuf::from("input.txt")), target: 100, ks: vec![2, 3, 4], all: true, format: Format::Text },
+                   Options::from_args(&args(&["--target", "100", "--k", "2,3,4", "--all", "input.txt"])).unwrap());
+
+        assert_eq!(Options { input: Input::File(PathBuf::from("input.txt")), target: -5, ks: vec![4], all: false, format: Format::Text },
+                   Options::from_args(&args(&["input.txt", "--k", "4", "--target", "-5"])).unwrap());
+
+        assert_eq!(Options { input: Input::Stdin, target: 2020, ks: vec![2, 3], all: false, format: Format::Text },
+                   Options::from_args(&args(&["-"])).unwrap());
+
+        assert_eq!(Options { input: Input::Inline(String::from("1721\n299")), target: 2020, ks: vec![2], all: false, format: Format::Text },
+                   Options::from_args(&args(&["--k", "2", "--input-string", "1721\\n299"])).unwrap());
+
+        assert_eq!(Options { input: Input::Stdin, target: 2020, ks: vec![2, 3], all: false, format: Format::Json },
+                   Options::from_args(&args(&["--format", "json", "-"])).unwrap());
+    }
+
+    #[test]
+    fn options_from_args_errors() {
+        assert!(Options::from_args(&args(&[])).is_err());
+        assert!(Options::from_args(&args(&["--target"])).is_err());
+        assert!(Options::from_args(&args(&["--target", "lots", "input.txt"])).is_err());
+        assert!(Options::from_args(&args(&["--k", "2,x", "input.txt"])).is_err());
+        assert!(Options::from_args(&args(&["--verbose", "input.txt"])).is_err());
+        assert!(Options::from_args(&args(&["input.txt", "other.txt"])).is_err());
+        assert!(Options::from_args(&args(&["input.txt", "--input-string", "1721"])).is_err());
+        assert!(Options::from_args(&args(&["--input-string"])).is_err());
+        assert!(Options::from_args(&args(&["--format", "json", "--all", "input.txt"])).is_err());
+        assert!(Options::from_args(&args(&["--format", "csv", "input.txt"])).is_err());
+    }
+
+    #[test]
+    fn describe_lines() {
+        assert_eq!("line 3", report::describe_lines(&[2]));
+        assert_eq!("lines 1 and 4", report::describe_lines(&[0, 3]));
+        assert_eq!("lines 2, 3 and 5", report::describe_lines(&[1, 2, 4]));
+    }
+
+    #[test]
+    fn format_product() {
+        assert_eq!("1721 * 299 = 514579", report::format_product(&[1721, 299]).unwrap());
+        assert_eq!("979 * 366 * 675 = 241861950", report::format_product(&[979, 366, 675]).unwrap());
+        assert!(report::format_product(&[i64::MAX, 2]).is_err());
+    }
+
+    #[test]
+    fn format_sum() {
+        assert_eq!("1721 + 299 = 2020", report::format_sum(&[1721, 299]));
+        assert_eq!("1 + 2 + 3 + 4 = 10", report::format_sum(&[1, 2, 3, 4]));
+    }
+}
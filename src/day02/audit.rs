@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use std::error;
+use std::io::BufRead;
+
+use crate::day02::password::PasswordPolicy;
+use crate::day02::policy::Policy;
+use crate::day02::stream;
+use crate::runner::{self, Format, Input};
+use crate::solution;
+
+pub const USAGE: &str = "Usage: day02 [--audit (count | positions)] [--format (text | json | csv)] [INPUT_FILE_PATH | - | --input-string INPUT]";
+
+/// Which reading of the database's policies to audit against.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Interpretation {
+    // How many times the character appears, as in part 1
+    Count,
+
+    // Which of two positions hold the character, as in part 2
+    Positions,
+}
+
+impl Interpretation {
+    pub fn from_arg(arg: &str) -> Result<Interpretation, Box<dyn error::Error>> {
+        match arg {
+            "count" => Ok(Interpretation::Count),
+            "positions" => Ok(Interpretation::Positions),
+            _ => simple_error::bail!("Unknown policy interpretation: {} (expected count or positions)", arg),
+        }
+    }
+
+    pub fn policy(&self, policy: &PasswordPolicy) -> Box<dyn Policy> {
+        match self {
+            Interpretation::Count => Box::new(policy.count_range()),
+            Interpretation::Positions => Box::new(policy.positions()),
+        }
+    }
+
+    pub fn audit<R: BufRead>(&self, reader: R) -> Result<Audit, Box<dyn error::Error>> {
+        Audit::run(reader, |policy| self.policy(policy))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ReportFormat {
+    Text,
+    Csv,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn from_arg(arg: &str) -> Result<ReportFormat, Box<dyn error::Error>> {
+        match arg {
+            "text" => Ok(ReportFormat::Text),
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            _ => simple_error::bail!("Unknown format: {} (expected text, json or csv)", arg),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Options {
+    pub input: Input,
+    pub audit: Option<Interpretation>,
+    pub format: ReportFormat,
+}
+
+impl Options {
+    pub fn from_args(args: &[String]) -> Result<Options, Box<dyn error::Error>> {
+        let mut input = None;
+        let mut audit = None;
+        let mut format = ReportFormat::Text;
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let source = match arg.as_str() {
+                "--audit" => {
+                    audit = Some(Interpretation::from_arg(args.next().ok_or("--audit needs a value")?)?);
+                    continue;
+                },
+                "--format" => {
+                    format = ReportFormat::from_arg(args.next().ok_or("--format needs a value")?)?;
+                    continue;
+                },
+                "--input-string" => Input::from_input_string(args.next().ok_or("--input-string needs a value")?),
+                _ if arg.starts_with("--") => simple_error::bail!("Unrecognized option: {}\n{}", arg, USAGE),
+                _ => Input::from_path_arg(arg),
+            };
+
+            if input.replace(source).is_some() {
+                simple_error::bail!(USAGE);
+            }
+        }
+
+        // Without an audit, there are only the puzzle's two answers, which have no CSV form
+        if format == ReportFormat::Csv && audit.is_none() {
+            simple_error::bail!("--format csv can only be used with --audit");
+        }
+
+        let input = input.unwrap_or_else(|| Input::File(runner::default_input_path(2)));
+
+        Ok(Options { input, audit, format })
+    }
+
+    /// The format for printing the puzzle's answers when not auditing.
+    pub fn answer_format(&self) -> Format {
+        match self.format {
+            ReportFormat::Json => Format::Json,
+            _ => Format::Text,
+        }
+    }
+}
+
+/// A password that failed its policy, and why.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Failure {
+    pub line: usize,
+    pub policy: String,
+    pub password: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct CharacterStats {
+    pub checked: usize,
+    pub failed: usize,
+}
+
+/// Every failing line in a password database, along with how each policy character fared.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Audit {
+    pub checked: usize,
+    pub failures: Vec<Failure>,
+    pub characters: BTreeMap<char, CharacterStats>,
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        String::from(field)
+    }
+}
+
+impl Audit {
+    /// Checks each line's password against the policy built from that line. Blank lines are
+    /// skipped, but lines that can't be parsed fail the whole audit.
+    pub fn run<R: BufRead, P: Policy>(reader: R, to_policy: impl Fn(&PasswordPolicy) -> P) -> Result<Audit, Box<dyn error::Error>> {
+        let mut audit = Audit {
+            checked: 0,
+            failures: Vec::new(),
+            characters: BTreeMap::new(),
+        };
+
+        for entry in stream::entries(reader) {
+            let (line, policy, password) = entry?;
+
+            let stats = audit.characters.entry(policy.character()).or_default();
+            stats.checked += 1;
+            audit.checked += 1;
+
+            if let Some(reason) = to_policy(&policy).failure(&password) {
+                stats.failed += 1;
+
+                audit.failures.push(Failure {
+                    line,
+                    policy: policy.to_string(),
+                    password,
+                    reason,
+                });
+            }
+        }
+
+        Ok(audit)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        for failure in &self.failures {
+            text.push_str(&format!("Line {} ({}: {}): {}\n", failure.line, failure.policy, failure.password, failure.reason));
+        }
+
+        text.push_str(&format!("{} of {} passwords failed\n", self.failures.len(), self.checked));
+
+        for (character, stats) in &self.characters {
+            text.push_str(&format!("  '{}': {} of {} failed\n", character, stats.failed, stats.checked));
+        }
+
+        text
+    }
+
+    /// One row per failing line, with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("line,policy,password,reason\n");
+
+        for failure in &self.failures {
+            csv.push_str(&format!("{},{},{},{}\n", failure.line, csv_field(&failure.policy),
+                                  csv_field(&failure.password), csv_field(&failure.reason)));
+        }
+
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        let failures: Vec<String> = self.failures.iter()
+            .map(|failure| format!("{{\"line\":{},\"policy\":{},\"password\":{},\"reason\":{}}}",
+                                   failure.line, solution::json_string(&failure.policy),
+                                   solution::json_string(&failure.password), solution::json_string(&failure.reason)))
+            .collect();
+
+        let characters: Vec<String> = self.characters.iter()
+            .map(|(character, stats)| format!("{}:{{\"checked\":{},\"failed\":{}}}",
+                                              solution::json_string(&character.to_string()), stats.checked, stats.failed))
+            .collect();
+
+        format!("{{\"checked\":{},\"failed\":{},\"failures\":[{}],\"characters\":{{{}}}}}",
+                self.checked, self.failures.len(), failures.join(","), characters.join(","))
+    }
+
+    pub fn format(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Text => self.to_text(),
+            ReportFormat::Csv => self.to_csv(),
+            ReportFormat::Json => format!("{}\n", self.to_json()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day02::audit::{Audit, CharacterStats, Interpretation, Options, ReportFormat};
+    use crate::runner::Input;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn audit_count() {
+        let audit = Interpretation::Count.audit("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n".as_bytes()).unwrap();
+
+        assert_eq!(3, audit.checked);
+        assert_eq!(1, audit.failures.len());
+        assert_eq!(2, audit.failures[0].line);
+        assert_eq!("1-3 b", audit.failures[0].policy);
+        assert_eq!("'b' appears 0 times, allowed 1–3", audit.failures[0].reason);
+        assert_eq!(Some(&CharacterStats { checked: 1, failed: 1 }), audit.characters.get(&'b'));
+    }
+
+    #[test]
+    fn audit_positions() {
+        let audit = Interpretation::Positions.audit("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-9 a: abc\n".as_bytes()).unwrap();
+
+        let reasons: Vec<&str> = audit.failures.iter().map(|failure| failure.reason.as_str()).collect();
+
+        assert_eq!(vec![
+            "neither position 1 nor 3 matches 'b'",
+            "positions 2 and 9 both match 'c'",
+            "Position 9 is past the end of a 3 character password",
+        ], reasons);
+
+        assert_eq!(Some(&CharacterStats { checked: 2, failed: 1 }), audit.characters.get(&'a'));
+        assert!(Interpretation::Positions.audit("1-3 a abcde".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn export() {
+        let audit = Interpretation::Count.audit("1-3 a: abcde\n1-2 ,: a,,,\"b\"\n".as_bytes()).unwrap();
+
+        assert_eq!("line,policy,password,reason\n2,\"1-2 ,\",\"a,,,\"\"b\"\"\",\"',' appears 3 times, allowed 1–2\"\n", audit.to_csv());
+
+        assert_eq!("{\"checked\":2,\"failed\":1,\
+                     \"failures\":[{\"line\":2,\"policy\":\"1-2 ,\",\"password\":\"a,,,\\\"b\\\"\",\"reason\":\"',' appears 3 times, allowed 1–2\"}],\
+                     \"characters\":{\",\":{\"checked\":1,\"failed\":1},\"a\":{\"checked\":1,\"failed\":0}}}",
+                   audit.to_json());
+
+        assert!(audit.to_text().starts_with("Line 2 (1-2 ,: a,,,\"b\"): "));
+        assert!(audit.to_text().ends_with("1 of 2 passwords failed\n  ',': 1 of 1 failed\n  'a': 0 of 1 failed\n"));
+    }
+
+    #[test]
+    fn options_from_args() {
+        let options = Options::from_args(&args(&["--audit", "positions", "--format", "csv", "-"])).unwrap();
+
+        assert_eq!(Options { input: Input::Stdin, audit: Some(Interpretation::Positions), format: ReportFormat::Csv }, options);
+
+        assert!(Options::from_args(&args(&["--format", "csv"])).is_err());
+        assert!(Options::from_args(&args(&["--audit", "length"])).is_err());
+        assert!(Options::from_args(&args(&["a.txt", "b.txt"])).is_err());
+    }
+
+    #[test]
+    fn audit_skips_blank_lines() {
+        let audit = Audit::run("\n1-3 a: abcde\n".as_bytes(), |policy| policy.count_range()).unwrap();
+
+        assert_eq!(1, audit.checked);
+    }
+}
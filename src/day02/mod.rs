@@ -0,0 +1,44 @@
+use std::error;
+
+use crate::day02::password::PasswordPolicy;
+use crate::day02::policy::Policy;
+use crate::solution::{Answer, Solution};
+
+pub mod audit;
+pub mod password;
+pub mod policy;
+pub mod stream;
+
+pub struct Day02;
+
+impl Day02 {
+    fn count_valid<P: Policy>(entries: &[(PasswordPolicy, String)], to_policy: fn(&PasswordPolicy) -> P) -> Result<Answer, Box<dyn error::Error>> {
+        let mut valid_passwords = 0;
+
+        for (policy, password) in entries {
+            if to_policy(policy).check(password)? {
+                valid_passwords += 1;
+            }
+        }
+
+        Ok(Answer::from(valid_passwords))
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<(PasswordPolicy, String)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        stream::entries(input.as_bytes())
+            .map(|entry| entry.map(|(_, policy, password)| (policy, password)))
+            .collect()
+    }
+
+    fn part1(&self, entries: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Day02::count_valid(entries, PasswordPolicy::count_range)
+    }
+
+    fn part2(&self, entries: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Day02::count_valid(entries, PasswordPolicy::positions)
+    }
+}
@@ -0,0 +1,184 @@
+use std::{error, fmt};
+
+use crate::day02::policy::{CountRange, Policy, PositionalXor};
+
+#[derive(Debug, PartialEq)]
+pub struct PasswordPolicy {
+    character: char,
+    min: u32,
+    max: u32,
+}
+
+pub fn parse(password_db_line: &str) -> Result<(PasswordPolicy, &str), Box<dyn error::Error>> {
+    let (policy, password) = password_db_line.split_once(": ")
+        .ok_or(simple_error::simple_error!("Could not find password separator in password database line"))?;
+
+    let (range, character) = policy.split_once(' ')
+        .ok_or(simple_error::simple_error!("Could not find space in password database line"))?;
+
+    let (min, max) = range.split_once('-')
+        .ok_or(simple_error::simple_error!("Could not find hyphen in password database line"))?;
+
+    // Work in chars rather than bytes so multi-byte policy characters come through whole
+    let mut chars = character.chars();
+
+    let character = match (chars.next(), chars.next()) {
+        (Some(character), None) => character,
+        _ => simple_error::bail!("Expected a single policy character but found \"{}\"", character),
+    };
+
+    Ok((PasswordPolicy {
+        character,
+        min: min.parse()?,
+        max: max.parse()?,
+    }, password))
+}
+
+impl PasswordPolicy {
+    pub fn character(&self) -> char {
+        self.character
+    }
+
+    /// Reads the policy as a range of how many times the character may appear.
+    pub fn count_range(&self) -> CountRange {
+        CountRange {
+            character: self.character,
+            min: self.min as usize,
+            max: self.max as usize,
+        }
+    }
+
+    /// Reads the policy as two positions, exactly one of which must hold the character. Positions
+    /// count chars from 1, not bytes.
+    pub fn positions(&self) -> PositionalXor {
+        PositionalXor {
+            character: self.character,
+            first: self.min as usize,
+            second: self.max as usize,
+        }
+    }
+}
+
+/// Writes the policy back out the way it appears in the database, like `1-3 a`.
+impl fmt::Display for PasswordPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.min, self.max, self.character)
+    }
+}
+
+pub fn is_valid_part1(policy: PasswordPolicy, password: &str) -> bool {
+    policy.count_range().is_satisfied_by(password)
+}
+
+pub fn is_valid_part2(policy: PasswordPolicy, password: &str) -> Result<bool, Box<dyn error::Error>> {
+    policy.positions().check(password)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day02::password;
+    use crate::day02::password::PasswordPolicy;
+
+    #[test]
+    fn parse() {
+        let (policy, password) = password::parse("1-3 a: abcde").unwrap();
+
+        assert_eq!(PasswordPolicy {
+            character: 'a',
+            min: 1,
+            max: 3
+        }, policy);
+
+        assert_eq!("abcde", password);
+    }
+
+    #[test]
+    fn is_valid_part1() {
+        {
+            let policy = PasswordPolicy {
+                character: 'a',
+                min: 1,
+                max: 3
+            };
+
+            assert!(password::is_valid_part1(policy, "abcde"));
+        }
+
+        {
+            let policy = PasswordPolicy {
+                character: 'b',
+                min: 1,
+                max: 3
+            };
+
+            assert!(!password::is_valid_part1(policy, "cdefg"));
+        }
+    }
+
+    #[test]
+    fn is_valid_part2() {
+        {
+            let policy = PasswordPolicy {
+                character: 'a',
+                min: 1,
+                max: 3
+            };
+
+            assert!(password::is_valid_part2(policy, "abcde").unwrap());
+        }
+
+        {
+            let policy = PasswordPolicy {
+                character: 'b',
+                min: 1,
+                max: 3
+            };
+
+            assert!(!password::is_valid_part2(policy, "cdefg").unwrap());
+        }
+
+        {
+            let policy = PasswordPolicy {
+                character: 'c',
+                min: 2,
+                max: 9
+            };
+
+            assert!(!password::is_valid_part2(policy, "ccccccccc").unwrap());
+        }
+    }
+
+    #[test]
+    fn parse_multi_byte() {
+        let (policy, password) = password::parse("2-4 é: ééaé").unwrap();
+
+        assert_eq!(PasswordPolicy {
+            character: 'é',
+            min: 2,
+            max: 4
+        }, policy);
+
+        assert_eq!("ééaé", password);
+        assert!(password::is_valid_part1(policy, password));
+
+        let (policy, password) = password::parse("1-3 ü: aüb").unwrap();
+        assert!(!password::is_valid_part2(policy, password).unwrap());
+
+        assert!(password::parse("1-3 ab: abc").is_err());
+        assert!(password::parse("1-3 : abc").is_err());
+        assert!(password::parse("1-x a: abc").is_err());
+    }
+
+    #[test]
+    fn is_valid_part2_impossible_positions() {
+        let (policy, password) = password::parse("0-2 a: abc").unwrap();
+        assert!(password::is_valid_part2(policy, password).is_err());
+
+        let (policy, password) = password::parse("1-4 a: abc").unwrap();
+        assert!(password::is_valid_part2(policy, password).is_err());
+
+        // Four chars, even though it's more than four bytes
+        let (policy, password) = password::parse("1-4 ö: öööx").unwrap();
+        assert!(password::is_valid_part2(policy, password).unwrap());
+    }
+}
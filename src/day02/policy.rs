@@ -0,0 +1,422 @@
+use std::{error, fmt};
+
+use regex::Regex;
+
+/// A rule a password either satisfies or doesn't. Rules can be combined with [`All`] and [`Any`] to
+/// build up more complicated policies.
+pub trait Policy {
+    fn is_satisfied_by(&self, password: &str) -> bool;
+
+    /// Like `is_satisfied_by`, but fails for rules that can't sensibly be applied to the password,
+    /// like a position past the end of it.
+    fn check(&self, password: &str) -> Result<bool, Box<dyn error::Error>> {
+        Ok(self.is_satisfied_by(password))
+    }
+
+    /// Why the password fails the policy, or `None` if it passes. Rules that can't be applied to the
+    /// password count as failures.
+    fn failure(&self, password: &str) -> Option<String> {
+        match self.check(password) {
+            Ok(true) => None,
+            Ok(false) => Some(String::from("doesn't satisfy the policy")),
+            Err(err) => Some(err.to_string()),
+        }
+    }
+}
+
+fn plural(count: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", count, if count == 1 { singular } else { plural })
+}
+
+impl<P: Policy + ?Sized> Policy for Box<P> {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        (**self).is_satisfied_by(password)
+    }
+
+    fn check(&self, password: &str) -> Result<bool, Box<dyn error::Error>> {
+        (**self).check(password)
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        (**self).failure(password)
+    }
+}
+
+/// The given character appears at least `min` and at most `max` times.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CountRange {
+    pub character: char,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Policy for CountRange {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        let count = password.chars().filter(|c| *c == self.character).count();
+
+        count >= self.min && count <= self.max
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        let count = password.chars().filter(|c| *c == self.character).count();
+
+        if count >= self.min && count <= self.max {
+            None
+        } else {
+            Some(format!("'{}' appears {}, allowed {}–{}", self.character, plural(count, "time", "times"), self.min, self.max))
+        }
+    }
+}
+
+/// The given character appears at exactly one of two positions, counting chars from 1. Positions
+/// outside the password never hold the character, though `check` reports them as errors.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PositionalXor {
+    pub character: char,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl PositionalXor {
+    fn is_at(&self, password: &str, position: usize) -> bool {
+        position > 0 && password.chars().nth(position - 1) == Some(self.character)
+    }
+}
+
+impl Policy for PositionalXor {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        self.is_at(password, self.first) ^ self.is_at(password, self.second)
+    }
+
+    fn check(&self, password: &str) -> Result<bool, Box<dyn error::Error>> {
+        let length = password.chars().count();
+
+        for position in [self.first, self.second] {
+            if position == 0 {
+                simple_error::bail!("Positions count from 1, so position 0 is never valid");
+            } else if position > length {
+                simple_error::bail!("Position {} is past the end of a {} character password", position, length);
+            }
+        }
+
+        Ok(self.is_satisfied_by(password))
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        if let Err(err) = self.check(password) {
+            return Some(err.to_string());
+        }
+
+        match (self.is_at(password, self.first), self.is_at(password, self.second)) {
+            (true, true) => Some(format!("positions {} and {} both match '{}'", self.first, self.second, self.character)),
+            (false, false) => Some(format!("neither position {} nor {} matches '{}'", self.first, self.second, self.character)),
+            _ => None,
+        }
+    }
+}
+
+/// The password matches a regular expression somewhere; anchor the pattern to match all of it.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    regex: Regex,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Pattern { regex: Regex::new(pattern)? })
+    }
+}
+
+impl Policy for Pattern {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        self.regex.is_match(password)
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        if self.is_satisfied_by(password) {
+            None
+        } else {
+            Some(format!("doesn't match /{}/", self.regex))
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum CharacterClass {
+    Lowercase,
+    Uppercase,
+
+    // Only the ASCII digits 0-9, not other numerals like '½' or 'Ⅻ'
+    Digit,
+
+    // Anything that isn't a letter, digit or whitespace
+    Symbol,
+}
+
+impl CharacterClass {
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            CharacterClass::Lowercase => c.is_lowercase(),
+            CharacterClass::Uppercase => c.is_uppercase(),
+            CharacterClass::Digit => c.is_ascii_digit(),
+            CharacterClass::Symbol => !c.is_alphabetic() && !c.is_ascii_digit() && !c.is_whitespace(),
+        }
+    }
+}
+
+impl fmt::Display for CharacterClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CharacterClass::Lowercase => write!(f, "lowercase"),
+            CharacterClass::Uppercase => write!(f, "uppercase"),
+            CharacterClass::Digit => write!(f, "digit"),
+            CharacterClass::Symbol => write!(f, "symbol"),
+        }
+    }
+}
+
+/// At least `min` characters of the password belong to the given class.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RequiredClass {
+    pub class: CharacterClass,
+    pub min: usize,
+}
+
+impl Policy for RequiredClass {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        password.chars().filter(|c| self.class.contains(*c)).count() >= self.min
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        let count = password.chars().filter(|c| self.class.contains(*c)).count();
+
+        if count >= self.min {
+            None
+        } else {
+            let found = plural(count, &format!("{} character", self.class), &format!("{} characters", self.class));
+            Some(format!("has {}, needs at least {}", found, self.min))
+        }
+    }
+}
+
+/// The password is at least this many characters long.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MinLength(pub usize);
+
+impl Policy for MinLength {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        password.chars().count() >= self.0
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        let length = password.chars().count();
+
+        if length >= self.0 {
+            None
+        } else {
+            Some(format!("is {} long, needs at least {}", plural(length, "character", "characters"), self.0))
+        }
+    }
+}
+
+/// None of the given strings appear anywhere in the password.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ForbiddenSubstrings(pub Vec<String>);
+
+impl Policy for ForbiddenSubstrings {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        !self.0.iter().any(|substring| password.contains(substring.as_str()))
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        self.0.iter()
+            .find(|substring| password.contains(substring.as_str()))
+            .map(|substring| format!("contains forbidden \"{}\"", substring))
+    }
+}
+
+/// Every one of the policies is satisfied; an empty list is always satisfied.
+pub struct All(pub Vec<Box<dyn Policy>>);
+
+impl Policy for All {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        self.0.iter().all(|policy| policy.is_satisfied_by(password))
+    }
+
+    fn check(&self, password: &str) -> Result<bool, Box<dyn error::Error>> {
+        for policy in &self.0 {
+            if !policy.check(password)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        let failures: Vec<String> = self.0.iter()
+            .filter_map(|policy| policy.failure(password))
+            .collect();
+
+        if failures.is_empty() {
+            None
+        } else {
+            Some(failures.join("; "))
+        }
+    }
+}
+
+/// At least one of the policies is satisfied; an empty list is never satisfied.
+pub struct Any(pub Vec<Box<dyn Policy>>);
+
+impl Policy for Any {
+    fn is_satisfied_by(&self, password: &str) -> bool {
+        self.0.iter().any(|policy| policy.is_satisfied_by(password))
+    }
+
+    // An alternative that can't be applied only matters if none of the others pass
+    fn check(&self, password: &str) -> Result<bool, Box<dyn error::Error>> {
+        let mut first_err = None;
+
+        for policy in &self.0 {
+            match policy.check(password) {
+                Ok(true) => return Ok(true),
+                Ok(false) => {},
+                Err(err) => {
+                    first_err.get_or_insert(err);
+                },
+            }
+        }
+
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(false),
+        }
+    }
+
+    fn failure(&self, password: &str) -> Option<String> {
+        let mut failures = Vec::with_capacity(self.0.len());
+
+        for policy in &self.0 {
+            failures.push(policy.failure(password)?);
+        }
+
+        Some(format!("none of the alternatives pass ({})", failures.join("; ")))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day02::policy::{All, Any, CharacterClass, CountRange, ForbiddenSubstrings, MinLength, Pattern, Policy, PositionalXor, RequiredClass};
+
+    #[test]
+    fn count_range() {
+        let policy = CountRange { character: 'a', min: 1, max: 3 };
+
+        assert!(policy.is_satisfied_by("abcde"));
+        assert!(!policy.is_satisfied_by("bcde"));
+        assert!(!policy.is_satisfied_by("aaaa"));
+    }
+
+    #[test]
+    fn positional_xor() {
+        let policy = PositionalXor { character: 'c', first: 2, second: 9 };
+
+        assert!(policy.is_satisfied_by("ccbbbbbbb"));
+        assert!(!policy.is_satisfied_by("ccccccccc"));
+        assert!(!policy.is_satisfied_by("abcde"));
+        assert!(policy.is_satisfied_by("ac"));
+
+        assert!(policy.check("ac").is_err());
+        assert!(PositionalXor { character: 'c', first: 0, second: 1 }.check("c").is_err());
+        assert!(All(vec![Box::new(MinLength(1)), Box::new(policy)]).check("ac").is_err());
+    }
+
+    #[test]
+    fn character_rules() {
+        assert!(Pattern::new("^[a-z]+[0-9]$").unwrap().is_satisfied_by("hunter2"));
+        assert!(!Pattern::new("^[a-z]+[0-9]$").unwrap().is_satisfied_by("Hunter2"));
+        assert!(Pattern::new("(").is_err());
+
+        assert!(RequiredClass { class: CharacterClass::Symbol, min: 2 }.is_satisfied_by("a!b?"));
+        assert!(!RequiredClass { class: CharacterClass::Uppercase, min: 1 }.is_satisfied_by("abc"));
+
+        assert!(CharacterClass::Digit.contains('7'));
+        assert!(!CharacterClass::Digit.contains('½'));
+        assert!(!CharacterClass::Digit.contains('²'));
+        assert!(!RequiredClass { class: CharacterClass::Digit, min: 1 }.is_satisfied_by("Ⅻ½"));
+        assert!(CharacterClass::Symbol.contains('½'));
+
+        assert!(MinLength(3).is_satisfied_by("abc"));
+        assert!(!MinLength(4).is_satisfied_by("abc"));
+
+        let forbidden = ForbiddenSubstrings(vec![String::from("password"), String::from("1234")]);
+        assert!(forbidden.is_satisfied_by("correct horse"));
+        assert!(!forbidden.is_satisfied_by("my-password!"));
+    }
+
+    #[test]
+    fn failure() {
+        assert_eq!(Some(String::from("'a' appears 5 times, allowed 1–3")),
+                   CountRange { character: 'a', min: 1, max: 3 }.failure("aaaaa"));
+        assert_eq!(None, CountRange { character: 'a', min: 1, max: 3 }.failure("abc"));
+
+        assert_eq!(Some(String::from("positions 1 and 3 both match 'a'")),
+                   PositionalXor { character: 'a', first: 1, second: 3 }.failure("aba"));
+        assert_eq!(Some(String::from("neither position 1 nor 3 matches 'a'")),
+                   PositionalXor { character: 'a', first: 1, second: 3 }.failure("bbb"));
+        assert_eq!(Some(String::from("Position 9 is past the end of a 3 character password")),
+                   PositionalXor { character: 'a', first: 1, second: 9 }.failure("abc"));
+
+        assert_eq!(Some(String::from("has 0 symbol characters, needs at least 1")),
+                   RequiredClass { class: CharacterClass::Symbol, min: 1 }.failure("abc"));
+        assert_eq!(Some(String::from("has 1 digit character, needs at least 2")),
+                   RequiredClass { class: CharacterClass::Digit, min: 2 }.failure("a1c"));
+        assert_eq!(Some(String::from("is 1 character long, needs at least 8")), MinLength(8).failure("a"));
+
+        let policy = All(vec![Box::new(MinLength(8)), Box::new(ForbiddenSubstrings(vec![String::from("pass")]))]);
+        assert_eq!(Some(String::from("is 4 characters long, needs at least 8; contains forbidden \"pass\"")), policy.failure("pass"));
+
+        let policy = Any(vec![Box::new(MinLength(8)), Box::new(Pattern::new("^a").unwrap())]);
+        assert_eq!(None, policy.failure("abc"));
+        assert_eq!(Some(String::from("none of the alternatives pass (is 3 characters long, needs at least 8; doesn't match /^a/)")),
+                   policy.failure("bcd"));
+    }
+
+    #[test]
+    fn combined() {
+        let policy = All(vec![
+            Box::new(MinLength(8)),
+            Box::new(ForbiddenSubstrings(vec![String::from("password")])),
+            Box::new(Any(vec![
+                Box::new(RequiredClass { class: CharacterClass::Digit, min: 1 }),
+                Box::new(RequiredClass { class: CharacterClass::Symbol, min: 1 }),
+            ])),
+        ]);
+
+        assert!(policy.is_satisfied_by("tr0ub4dor"));
+        assert!(policy.is_satisfied_by("troubador!"));
+        assert!(!policy.is_satisfied_by("troubador"));
+        assert!(!policy.is_satisfied_by("password1"));
+        assert!(!policy.is_satisfied_by("tr0ub"));
+
+        assert!(All(vec![]).is_satisfied_by(""));
+        assert!(!Any(vec![]).is_satisfied_by(""));
+    }
+
+    #[test]
+    fn any_with_inapplicable_alternative() {
+        let out_of_range = || Box::new(PositionalXor { character: 'c', first: 1, second: 9 });
+
+        // Another alternative passing makes up for one that can't be applied
+        let policy = Any(vec![out_of_range(), Box::new(MinLength(1))]);
+        assert!(policy.is_satisfied_by("ab"));
+        assert!(policy.check("ab").unwrap());
+        assert_eq!(None, policy.failure("ab"));
+
+        let policy = Any(vec![out_of_range(), Box::new(MinLength(5))]);
+        assert!(!policy.is_satisfied_by("ab"));
+        assert!(policy.check("ab").is_err());
+        assert!(policy.failure("ab").is_some());
+    }
+}
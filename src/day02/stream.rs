@@ -0,0 +1,112 @@
+use std::error;
+use std::io::BufRead;
+
+use crate::day02::password::{self, PasswordPolicy};
+use crate::day02::policy::Policy;
+
+/// One way of reading a database line's policy, like [`PasswordPolicy::count_range`].
+pub type Interpret<'a> = &'a dyn Fn(&PasswordPolicy) -> Box<dyn Policy>;
+
+/// Reads password database entries one line at a time, so the whole database never has to be in
+/// memory at once.
+pub struct Entries<R> {
+    reader: R,
+    buffer: String,
+    line: usize,
+}
+
+/// Each entry in a password database, along with its line number. Blank lines are skipped.
+pub fn entries<R: BufRead>(reader: R) -> Entries<R> {
+    Entries {
+        reader,
+        buffer: String::new(),
+        line: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Entries<R> {
+    type Item = Result<(usize, PasswordPolicy, String), Box<dyn error::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+            self.line += 1;
+
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => {},
+                Err(err) => return Some(Err(simple_error::simple_error!("Line {}: {}", self.line, err).into())),
+            }
+
+            let line = self.buffer.trim_end_matches(&['\n', '\r'][..]);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            return Some(password::parse(line)
+                .map(|(policy, password)| (self.line, policy, String::from(password)))
+                .map_err(|err| simple_error::simple_error!("Line {}: {}", self.line, err).into()));
+        }
+    }
+}
+
+/// Counts the passwords that satisfy each interpretation of their policies, in a single pass over
+/// the database.
+pub fn count_valid<R: BufRead>(reader: R, interpretations: &[Interpret]) -> Result<Vec<usize>, Box<dyn error::Error>> {
+    let mut counts = vec![0; interpretations.len()];
+
+    for entry in entries(reader) {
+        let (line, policy, password) = entry?;
+
+        for (count, interpret) in counts.iter_mut().zip(interpretations) {
+            if interpret(&policy).check(&password)
+                .map_err(|err| simple_error::simple_error!("Line {}: {}", line, err))? {
+                *count += 1;
+            }
+        }
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day02::password::PasswordPolicy;
+    use crate::day02::policy::{MinLength, Policy};
+    use crate::day02::stream;
+
+    const EXAMPLE: &str = "1-3 a: abcde\n1-3 b: cdefg\r\n\n2-9 c: ccccccccc";
+
+    #[test]
+    fn entries() {
+        let entries: Vec<(usize, String, String)> = stream::entries(EXAMPLE.as_bytes())
+            .map(|entry| entry.map(|(line, policy, password)| (line, policy.to_string(), password)).unwrap())
+            .collect();
+
+        assert_eq!(vec![
+            (1, String::from("1-3 a"), String::from("abcde")),
+            (2, String::from("1-3 b"), String::from("cdefg")),
+            (4, String::from("2-9 c"), String::from("ccccccccc")),
+        ], entries);
+
+        let error = stream::entries("1-3 a: abcde\n1-3 b cdefg\n".as_bytes())
+            .find_map(Result::err)
+            .unwrap();
+
+        assert!(error.to_string().starts_with("Line 2: "));
+    }
+
+    #[test]
+    fn count_valid() {
+        let count_range = |policy: &PasswordPolicy| Box::new(policy.count_range()) as Box<dyn Policy>;
+        let positions = |policy: &PasswordPolicy| Box::new(policy.positions()) as Box<dyn Policy>;
+        let long = |_: &PasswordPolicy| Box::new(MinLength(6)) as Box<dyn Policy>;
+
+        assert_eq!(vec![2, 1, 1], stream::count_valid(EXAMPLE.as_bytes(), &[&count_range, &positions, &long]).unwrap());
+        assert_eq!(Vec::<usize>::new(), stream::count_valid(EXAMPLE.as_bytes(), &[]).unwrap());
+
+        let error = stream::count_valid("1-9 a: abc\n".as_bytes(), &[&positions]).unwrap_err();
+        assert_eq!("Line 1: Position 9 is past the end of a 3 character password", error.to_string());
+    }
+}
@@ -0,0 +1,32 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day03::treemap::TreeMap;
+
+pub mod treemap;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = TreeMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(TreeMap::from_lines(input.lines().map(String::from)))
+    }
+
+    fn part1(&self, tree_map: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(tree_map.get_collisions(1, 3)?))
+    }
+
+    fn part2(&self, tree_map: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let slopes: [(usize, usize); 5] = [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
+
+        let mut collision_product = 1u64;
+
+        for (delta_row, delta_col) in &slopes {
+            collision_product *= tree_map.get_collisions(*delta_row, *delta_col)? as u64;
+        }
+
+        Ok(Answer::from(collision_product))
+    }
+}
@@ -0,0 +1,165 @@
+use std::error;
+use std::fs::File;
+use std::io;
+use std::io::BufRead;
+
+const TREE_CHAR: char = '#';
+
+/// A cell passed through on the way down the map. The map repeats to the left and right, so columns
+/// are positions on the repeated map and can be negative.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Visit {
+    pub row: usize,
+    pub col: isize,
+    pub tree: bool,
+}
+
+pub struct TreeMap {
+    map: Vec<Vec<bool>>,
+    width: usize,
+    height: usize,
+}
+
+impl TreeMap {
+    // Unreadable lines are skipped rather than ending the map early
+    #[allow(clippy::lines_filter_map_ok)]
+    pub fn from_file(file: &File) -> TreeMap {
+        TreeMap::from_lines(io::BufReader::new(file).lines()
+            .filter_map(|line| line.ok()))
+    }
+
+    pub fn from_lines<I>(lines: I) -> TreeMap
+    where
+        I: Iterator<Item = String>,
+    {
+        let map: Vec<Vec<bool>> = lines.map(|line| line.chars().map(|c| c == TREE_CHAR).collect()).collect();
+        let height = map.len();
+        let width = map[0].len();
+
+        TreeMap {
+            map,
+            width,
+            height
+        }
+    }
+
+    /// Counts the trees hit going down the map at the given slope, not counting the starting cell.
+    /// Fails if the slope doesn't move down, since it would never reach the bottom.
+    pub fn get_collisions(&self, delta_row: usize, delta_col: usize) -> Result<u32, Box<dyn error::Error>> {
+        let steps = [(delta_row as isize, delta_col as isize)];
+
+        let collisions = self.walk(&steps)?.skip(1).filter(|visit| visit.tree).count();
+
+        Ok(collisions as u32)
+    }
+
+    /// Walks the map from the top-left cell, taking the given (delta_row, delta_col) steps in order
+    /// and starting over from the first step after the last, until the path leaves the top or bottom
+    /// of the map. Every visited cell is returned, including the starting cell.
+    pub fn traverse(&self, steps: &[(isize, isize)]) -> Result<Vec<Visit>, Box<dyn error::Error>> {
+        Ok(self.walk(steps)?.collect())
+    }
+
+    fn walk<'a>(&'a self, steps: &'a [(isize, isize)]) -> Result<impl Iterator<Item = Visit> + 'a, Box<dyn error::Error>> {
+        // Unless each pass through the steps moves down, the path could go around in circles forever
+        if steps.iter().map(|(delta_row, _)| delta_row).sum::<isize>() <= 0 {
+            simple_error::bail!("Steps must move down the map overall");
+        }
+
+        let height = self.height as isize;
+
+        let positions = steps.iter().cycle().scan((0, 0), |position: &mut (isize, isize), (delta_row, delta_col)| {
+            let current = *position;
+            *position = (current.0 + delta_row, current.1 + delta_col);
+
+            Some(current)
+        });
+
+        Ok(positions
+            .take_while(move |(row, _)| *row >= 0 && *row < height)
+            .map(move |(row, col)| Visit { row: row as usize, col, tree: self.is_tree(row as usize, col) }))
+    }
+
+    fn is_tree(&self, row: usize, col: isize) -> bool {
+        self.map[row][col.rem_euclid(self.width as isize) as usize]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day03::treemap::{TreeMap, Visit};
+
+    const EXAMPLE: [&str; 11] = [
+        "..##.......",
+        "#...#...#..",
+        ".#....#..#.",
+        "..#.#...#.#",
+        ".#...##..#.",
+        "..#.##.....",
+        ".#.#.#....#",
+        ".#........#",
+        "#.##...#...",
+        "#...##....#",
+        ".#..#...#.#",
+    ];
+
+    fn example() -> TreeMap {
+        TreeMap::from_lines(EXAMPLE.iter().map(|line| line.to_string()))
+    }
+
+    #[test]
+    fn from_lines() {
+        let tree_map = TreeMap::from_lines(vec!["..##.......".to_string()].into_iter());
+
+        assert_eq!(11, tree_map.width);
+        assert_eq!(1, tree_map.height);
+    }
+
+    #[test]
+    fn is_tree() {
+        let tree_map = TreeMap::from_lines(vec!["..##.......".to_string()].into_iter());
+
+        assert!(tree_map.is_tree(0, 2));
+        assert!(tree_map.is_tree(0, 13));
+        assert!(!tree_map.is_tree(0, 1));
+    }
+
+    #[test]
+    fn get_collisions() {
+        let tree_map = example();
+
+        assert_eq!(7, tree_map.get_collisions(1, 3).unwrap());
+        assert_eq!(2, tree_map.get_collisions(2, 1).unwrap());
+        assert!(tree_map.get_collisions(0, 1).is_err());
+    }
+
+    #[test]
+    fn traverse() {
+        let tree_map = example();
+
+        let visits = tree_map.traverse(&[(1, 3)]).unwrap();
+        assert_eq!(11, visits.len());
+        assert_eq!(Visit { row: 10, col: 30, tree: true }, visits[10]);
+        assert_eq!(7, visits.iter().skip(1).filter(|visit| visit.tree).count());
+
+        // Going left wraps around just like going right
+        let visits = tree_map.traverse(&[(1, -1)]).unwrap();
+        assert_eq!(Visit { row: 1, col: -1, tree: false }, visits[1]);
+        assert_eq!(Visit { row: 3, col: -3, tree: true }, visits[3]);
+    }
+
+    #[test]
+    fn traverse_step_sequence() {
+        let tree_map = example();
+
+        // Knight moves, zig-zagging left and right
+        let visits = tree_map.traverse(&[(2, 1), (2, -1), (-1, 2)]).unwrap();
+
+        let cells: Vec<(usize, isize)> = visits.iter().map(|visit| (visit.row, visit.col)).collect();
+        assert_eq!(vec![(0, 0), (2, 1), (4, 0), (3, 2), (5, 3), (7, 2), (6, 4), (8, 5), (10, 4), (9, 6)], cells);
+
+        assert!(tree_map.traverse(&[]).is_err());
+        assert!(tree_map.traverse(&[(0, 1)]).is_err());
+        assert!(tree_map.traverse(&[(1, 1), (-1, 1)]).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use std::collections::HashMap;
+use std::error;
+
+use crate::solution::{Answer, Solution};
+
+pub mod passport;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<HashMap<String, String>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.split("\n\n")
+            .map(|passport| passport::parse(passport.to_string()))
+            .collect())
+    }
+
+    fn part1(&self, passports: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(passports.iter().filter(|passport| passport::has_required_fields(passport)).count()))
+    }
+
+    fn part2(&self, passports: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(passports.iter().filter(|passport| passport::is_valid(passport)).count()))
+    }
+}
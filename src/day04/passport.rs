@@ -75,7 +75,7 @@ pub fn is_valid(passport: &HashMap<String, String>) -> bool {
     true
 }
 
-fn year_valid(year: &String, min: u32, max: u32) -> bool {
+fn year_valid(year: &str, min: u32, max: u32) -> bool {
     if let Ok(year) = year.parse::<u32>() {
         year >= min && year <= max
     } else {
@@ -83,7 +83,7 @@ fn year_valid(year: &String, min: u32, max: u32) -> bool {
     }
 }
 
-fn height_valid(height: &String) -> bool {
+fn height_valid(height: &str) -> bool {
     lazy_static! {
         static ref HEIGHT_RE: Regex = Regex::new("([0-9]+)(in|cm)").unwrap();
     }
@@ -93,16 +93,16 @@ fn height_valid(height: &String) -> bool {
         let unit = captures[2].to_ascii_lowercase();
 
         if unit == "in" {
-            magnitude >= 59 && magnitude <= 76
+            (59..=76).contains(&magnitude)
         } else {
-            magnitude >= 150 && magnitude <= 193
+            (150..=193).contains(&magnitude)
         }
     } else {
         false
     }
 }
 
-fn hair_color_valid(hair_color: &String) -> bool {
+fn hair_color_valid(hair_color: &str) -> bool {
     lazy_static! {
         static ref HAIR_COLOR_RE: Regex = Regex::new("^#[0-9a-fA-F]{6}$").unwrap();
     }
@@ -110,11 +110,11 @@ fn hair_color_valid(hair_color: &String) -> bool {
     HAIR_COLOR_RE.is_match(hair_color)
 }
 
-fn eye_color_valid(eye_color: &String) -> bool {
+fn eye_color_valid(eye_color: &str) -> bool {
     VALID_EYE_COLORS.contains(eye_color)
 }
 
-fn passport_id_valid(passport_id: &String) -> bool {
+fn passport_id_valid(passport_id: &str) -> bool {
     lazy_static! {
         static ref PASSPORT_ID_RE: Regex = Regex::new("^[0-9]{9}$").unwrap();
     }
@@ -124,7 +124,7 @@ fn passport_id_valid(passport_id: &String) -> bool {
 
 #[cfg(test)]
 mod test {
-    use crate::passport;
+    use crate::day04::passport;
     use std::collections::HashMap;
 
     #[test]
@@ -0,0 +1,37 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day05::seating::Seat;
+
+pub mod seating;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Seat>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.lines().map(Seat::from_code).collect())
+    }
+
+    fn part1(&self, seats: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        match seats.iter().map(|seat| seat.get_id()).max() {
+            Some(max_id) => Ok(Answer::from(max_id)),
+            None => simple_error::bail!("No boarding passes in input"),
+        }
+    }
+
+    fn part2(&self, seats: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let mut candidate_seats: Vec<u32> = seats.iter()
+            .filter(|seat| seat.row != 0 && seat.row != 127)
+            .map(|seat| seat.get_id())
+            .collect();
+
+        candidate_seats.sort_unstable();
+
+        match candidate_seats.windows(2).find(|pair| pair[1] - pair[0] != 1) {
+            Some(pair) => Ok(Answer::from(pair[1] - 1)),
+            None => simple_error::bail!("No gap in seat IDs"),
+        }
+    }
+}
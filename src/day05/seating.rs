@@ -29,7 +29,7 @@ impl Seat {
 
 #[cfg(test)]
 mod test {
-    use crate::seating::Seat;
+    use crate::day05::seating::Seat;
 
     #[test]
     fn from_code() {
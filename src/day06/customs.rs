@@ -31,7 +31,7 @@ impl FormGroup {
 
 #[cfg(test)]
 mod test {
-    use crate::customs::FormGroup;
+    use crate::day06::customs::FormGroup;
     use std::collections::HashSet;
 
     #[test]
@@ -0,0 +1,24 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day06::customs::FormGroup;
+
+pub mod customs;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<FormGroup>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.split("\n\n").map(FormGroup::from_answers).collect())
+    }
+
+    fn part1(&self, form_groups: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(form_groups.iter().map(|form_group| form_group.get_distinct_answers().len()).sum::<usize>()))
+    }
+
+    fn part2(&self, form_groups: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(form_groups.iter().map(|form_group| form_group.get_universal_answers().len()).sum::<usize>()))
+    }
+}
@@ -92,7 +92,7 @@ impl FromIterator<String> for BagRules {
 
 #[cfg(test)]
 mod test {
-    use crate::bags::BagRules;
+    use crate::day07::bags::BagRules;
     use std::collections::HashMap;
 
     #[test]
@@ -122,15 +122,15 @@ mod test {
                                    String::from("faded blue bags contain no other bags."),
                                    String::from("dotted black bags contain no other bags."),].into_iter().collect();
 
-        assert_eq!(true, rules.has_path(&String::from("light red"), &String::from("shiny gold")));
-        assert_eq!(true, rules.has_path(&String::from("dark orange"), &String::from("shiny gold")));
-        assert_eq!(true, rules.has_path(&String::from("bright white"), &String::from("shiny gold")));
-        assert_eq!(true, rules.has_path(&String::from("muted yellow"), &String::from("shiny gold")));
-        assert_eq!(false, rules.has_path(&String::from("shiny gold"), &String::from("shiny gold")));
-        assert_eq!(false, rules.has_path(&String::from("dark olive"), &String::from("shiny gold")));
-        assert_eq!(false, rules.has_path(&String::from("vibrant plum"), &String::from("shiny gold")));
-        assert_eq!(false, rules.has_path(&String::from("faded blue"), &String::from("shiny gold")));
-        assert_eq!(false, rules.has_path(&String::from("dotted black"), &String::from("shiny gold")));
+        assert!(rules.has_path(&String::from("light red"), &String::from("shiny gold")));
+        assert!(rules.has_path(&String::from("dark orange"), &String::from("shiny gold")));
+        assert!(rules.has_path(&String::from("bright white"), &String::from("shiny gold")));
+        assert!(rules.has_path(&String::from("muted yellow"), &String::from("shiny gold")));
+        assert!(!rules.has_path(&String::from("shiny gold"), &String::from("shiny gold")));
+        assert!(!rules.has_path(&String::from("dark olive"), &String::from("shiny gold")));
+        assert!(!rules.has_path(&String::from("vibrant plum"), &String::from("shiny gold")));
+        assert!(!rules.has_path(&String::from("faded blue"), &String::from("shiny gold")));
+        assert!(!rules.has_path(&String::from("dotted black"), &String::from("shiny gold")));
     }
 
     #[test]
@@ -0,0 +1,24 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day07::bags::BagRules;
+
+pub mod bags;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = BagRules;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, rules: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(rules.get_top_level_containers(&String::from("shiny gold"))))
+    }
+
+    fn part2(&self, rules: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(rules.get_total_contained_bags(&String::from("shiny gold"))))
+    }
+}
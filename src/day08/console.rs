@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use crate::day08::console::Opcode::{Nop, Jmp};
+use crate::day08::console::Exit::Terminate;
+use crate::parse::ParseError;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Instruction {
+    opcode: Opcode,
+    value: i32,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Opcode {
+    Nop,
+    Acc,
+    Jmp,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Exit {
+    Loop(i32),
+    Terminate(i32),
+}
+
+pub fn eval(instructions: &[Instruction]) -> Exit {
+    let mut visited: Vec<bool> = vec![false; instructions.len()];
+    let mut i = 0;
+    let mut acc = 0;
+
+    while i < instructions.len() {
+        if visited[i] {
+            return Exit::Loop(acc);
+        }
+
+        visited[i] = true;
+
+        match &instructions[i].opcode {
+            Opcode::Nop => i += 1,
+            Opcode::Jmp => i = ((i as isize) + instructions[i].value as isize) as usize,
+            Opcode::Acc => {
+                acc += instructions[i].value;
+                i += 1;
+            }
+        }
+    }
+
+    Terminate(acc)
+}
+
+pub fn get_acc_after_repair(instructions: &[Instruction]) -> i32 {
+    for candidate in 0..instructions.len() {
+        if instructions[candidate].opcode == Opcode::Acc {
+            continue;
+        }
+
+        let mut repaired = instructions.to_vec();
+
+        if repaired[candidate].opcode == Nop {
+            repaired[candidate].opcode = Jmp;
+        } else {
+            repaired[candidate].opcode = Nop;
+        }
+
+        if let Terminate(acc) = eval(&repaired) {
+            return acc;
+        }
+    }
+
+    unreachable!()
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (opcode, value) = line.split_once(' ')
+            .ok_or_else(|| ParseError::new(line, line, "Expected an opcode and a value"))?;
+
+        let opcode = match opcode {
+            "nop" => Opcode::Nop,
+            "acc" => Opcode::Acc,
+            "jmp" => Opcode::Jmp,
+            _ => return Err(ParseError::new(line, opcode, "Unknown opcode")),
+        };
+
+        let value = value.parse()
+            .map_err(|_| ParseError::new(line, value, "Invalid value"))?;
+
+        Ok(Instruction { opcode, value })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day08::console::{Instruction, Opcode, Exit};
+    use crate::day08::console;
+    use crate::parse::{self, ParseError};
+
+    #[test]
+    fn instruction_from_str() {
+        assert_eq!(Ok(Instruction { opcode: Opcode::Acc, value: 14 }), "acc +14".parse());
+        assert_eq!(Ok(Instruction { opcode: Opcode::Jmp, value: -3 }), "jmp -3".parse());
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("add"), message: String::from("Unknown opcode") }),
+                   "add +1".parse::<Instruction>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from("+one"), message: String::from("Invalid value") }),
+                   "acc +one".parse::<Instruction>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("nop"), message: String::from("Expected an opcode and a value") }),
+                   "nop".parse::<Instruction>());
+    }
+
+    #[test]
+    fn instructions_report_line_numbers() {
+        let err = parse::parse_lines::<Instruction>("nop +0\nacc +1\njmp four\n").unwrap_err();
+
+        assert_eq!((3, 5), (err.line, err.column));
+    }
+
+    #[test]
+    fn eval() {
+        {
+            let instructions: Vec<Instruction> = parse::parse_lines("nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6").unwrap();
+
+            assert_eq!(Exit::Loop(5), console::eval(&instructions));
+        }
+
+        {
+            let instructions: Vec<Instruction> = parse::parse_lines("nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\nnop -4\nacc +6").unwrap();
+
+            assert_eq!(Exit::Terminate(8), console::eval(&instructions));
+        }
+    }
+
+    #[test]
+    fn get_acc_after_repair() {
+        let instructions: Vec<Instruction> = parse::parse_lines("nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6").unwrap();
+
+        assert_eq!(8, console::get_acc_after_repair(&instructions));
+    }
+}
\ No newline at end of file
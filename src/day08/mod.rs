@@ -0,0 +1,28 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day08::console::{Exit, Instruction};
+use crate::parse;
+
+pub mod console;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        match console::eval(instructions) {
+            Exit::Loop(acc) => Ok(Answer::from(acc)),
+            Exit::Terminate(_) => simple_error::bail!("Program terminated without looping"),
+        }
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(console::get_acc_after_repair(instructions)))
+    }
+}
@@ -0,0 +1,48 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+
+pub mod xmas;
+
+const WINDOW_SIZE: usize = 25;
+
+pub struct Day09;
+
+impl Day09 {
+    fn find_outlier(numbers: &[i64]) -> Result<i64, Box<dyn error::Error>> {
+        if numbers.len() <= WINDOW_SIZE {
+            simple_error::bail!("Expected more than {} numbers, found {}", WINDOW_SIZE, numbers.len());
+        }
+
+        match xmas::find_outlier(numbers, WINDOW_SIZE)? {
+            Some(outlier) => Ok(outlier),
+            None => simple_error::bail!("No outlier found"),
+        }
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.lines().map(|line| line.parse()).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(Day09::find_outlier(numbers)?))
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let outlier = Day09::find_outlier(numbers)?;
+
+        match xmas::find_consecutive_sum(numbers, outlier) {
+            Some(sequence) => {
+                let min = sequence.iter().min().unwrap();
+                let max = sequence.iter().max().unwrap();
+
+                Ok(Answer::from(min + max))
+            },
+            None => simple_error::bail!("No sequence adds up to {}", outlier),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Returns `None` if there are no numbers past the first window to check
+pub fn find_outlier(numbers: &[i64], window_size: usize) -> Result<Option<i64>, ksum::KSumError> {
+    for offset in 0..numbers.len().saturating_sub(window_size) {
+        let candidate = numbers[offset + window_size];
+
+        // The two numbers have to come from different positions in the window, even if they're equal
+        if ksum::ksum_indices(&numbers[offset..(offset + window_size)], 2, candidate)?.is_empty() {
+            return Ok(Some(candidate))
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn find_consecutive_sum(numbers: &[i64], target: i64) -> Option<&[i64]> {
+    for window_size in 2..numbers.len() {
+        for offset in 0..(numbers.len() - window_size) {
+            if target == numbers[offset..(offset + window_size)].iter().sum() {
+                return Some(&numbers[offset..(offset + window_size)]);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day09::xmas;
+
+    #[test]
+    fn find_outliet() {
+        let numbers = [35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576];
+
+        assert_eq!(Ok(Some(127)), xmas::find_outlier(&numbers, 5));
+        assert_eq!(Ok(None), xmas::find_outlier(&numbers[..5], 5));
+
+        // 10 is only the sum of a pair if 5 appears twice
+        assert_eq!(Ok(None), xmas::find_outlier(&[5, 1, 5, 10], 3));
+        assert_eq!(Ok(Some(10)), xmas::find_outlier(&[5, 1, 2, 10], 3));
+        assert_eq!(Ok(None), xmas::find_outlier(&[], 5));
+    }
+
+    #[test]
+    fn find_consecutive_sum() {
+        let numbers = [35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576];
+
+        assert_eq!(Some(&numbers[2..6]), xmas::find_consecutive_sum(&numbers, 127));
+        assert_eq!(None, xmas::find_consecutive_sum(&numbers[..1], 35));
+        assert_eq!(None, xmas::find_consecutive_sum(&[], 0));
+    }
+}
\ No newline at end of file
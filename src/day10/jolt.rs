@@ -1,7 +1,7 @@
 use std::cmp::max;
 
-pub fn get_deltas(adapters: &mut Vec<u32>) -> [u32; 3] {
-    assert!(adapters.len() > 0);
+pub fn get_deltas(adapters: &mut [u32]) -> [u32; 3] {
+    assert!(!adapters.is_empty());
 
     adapters.sort();
 
@@ -22,8 +22,8 @@ pub fn get_deltas(adapters: &mut Vec<u32>) -> [u32; 3] {
     deltas
 }
 
-pub fn count_adapter_chains(adapters: &mut Vec<u32>) -> u64 {
-    assert!(adapters.len() > 0);
+pub fn count_adapter_chains(adapters: &mut [u32]) -> u64 {
+    assert!(!adapters.is_empty());
 
     adapters.sort();
 
@@ -31,9 +31,9 @@ pub fn count_adapter_chains(adapters: &mut Vec<u32>) -> u64 {
 
     chains[0] = 1;
 
-    for i in 0..adapters.len() {
-        let start = max(0, adapters[i] as i64 - 3) as usize;
-        chains[adapters[i] as usize] = chains[start..(adapters[i] as usize)].iter().sum();
+    for adapter in adapters.iter() {
+        let start = max(0, *adapter as i64 - 3) as usize;
+        chains[*adapter as usize] = chains[start..(*adapter as usize)].iter().sum();
     }
 
     chains[chains.len() - 1]
@@ -41,7 +41,7 @@ pub fn count_adapter_chains(adapters: &mut Vec<u32>) -> u64 {
 
 #[cfg(test)]
 mod test {
-    use crate::jolt;
+    use crate::day10::jolt;
 
     #[test]
     pub fn get_deltas() {
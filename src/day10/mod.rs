@@ -0,0 +1,25 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+
+pub mod jolt;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.lines().map(|line| line.parse()).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, adapters: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let deltas = jolt::get_deltas(&mut adapters.clone());
+
+        Ok(Answer::from(deltas[0] * deltas[2]))
+    }
+
+    fn part2(&self, adapters: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(jolt::count_adapter_chains(&mut adapters.clone())))
+    }
+}
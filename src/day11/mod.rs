@@ -0,0 +1,28 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day11::seating::SeatingMap;
+
+pub mod seating;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(String::from(input))
+    }
+
+    fn part1(&self, layout: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let stable_map = SeatingMap::from_layout(layout.clone()).into_stable_configuration_by_adjacency();
+
+        Ok(Answer::from(stable_map.get_occupied_seats()))
+    }
+
+    fn part2(&self, layout: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let stable_map = SeatingMap::from_layout(layout.clone()).into_stable_configuration_by_visibility();
+
+        Ok(Answer::from(stable_map.get_occupied_seats()))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::seating::Cell::{EmptySeat, OccupiedSeat, Floor};
+use crate::day11::seating::Cell::{EmptySeat, OccupiedSeat, Floor};
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct SeatingMap {
@@ -48,10 +48,8 @@ impl SeatingMap {
 
         for r in min_row..=max_row {
             for c in min_col..=max_col {
-                if r != row || c != col {
-                    if self.cell_at(r, c) == &OccupiedSeat {
-                        occupied_neighbors += 1;
-                    }
+                if (r != row || c != col) && self.cell_at(r, c) == &OccupiedSeat {
+                    occupied_neighbors += 1;
                 }
             }
         }
@@ -144,9 +142,9 @@ impl SeatingMap {
 
 #[cfg(test)]
 mod test {
-    use crate::seating;
-    use crate::seating::SeatingMap;
-    use crate::seating::Cell::{Floor, EmptySeat, OccupiedSeat};
+    use crate::day11::seating;
+    use crate::day11::seating::SeatingMap;
+    use crate::day11::seating::Cell::{Floor, EmptySeat, OccupiedSeat};
 
     #[test]
     fn from_layout() {
@@ -0,0 +1,36 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day12::navigation::{FerryPosition, Instruction};
+use crate::parse;
+
+pub mod navigation;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let mut position = FerryPosition::default();
+        position.apply_ferry(instructions.iter().cloned());
+
+        Ok(Answer::from(position.x.abs() + position.y.abs()))
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let mut position = FerryPosition {
+            x: 0,
+            y: 0,
+            heading: (10, 1),
+        };
+
+        position.apply_waypoint(instructions.iter().cloned());
+
+        Ok(Answer::from(position.x.abs() + position.y.abs()))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::navigation::Instruction::{Translate, Rotate, Forward};
+use std::str::FromStr;
+
+use crate::day12::navigation::Instruction::{Translate, Rotate, Forward};
+use crate::parse::ParseError;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct FerryPosition {
@@ -7,7 +10,7 @@ pub struct FerryPosition {
     pub heading: (i32, i32),
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub enum Instruction {
     Translate((i32, i32), i32),
     Rotate(i32),
@@ -15,7 +18,7 @@ pub enum Instruction {
 }
 
 fn air_quotes_sine(angle: i32) -> i32 {
-    let angle = ((angle % 360) + 360) % 360;
+    let angle = angle.rem_euclid(360);
 
     match angle {
         0 => 0,
@@ -27,7 +30,7 @@ fn air_quotes_sine(angle: i32) -> i32 {
 }
 
 fn air_quotes_cosine(angle: i32) -> i32 {
-    let angle = ((angle % 360) + 360) % 360;
+    let angle = angle.rem_euclid(360);
 
     match angle {
         0 => 1,
@@ -38,19 +41,25 @@ fn air_quotes_cosine(angle: i32) -> i32 {
     }
 }
 
-impl From<&str> for Instruction {
-    fn from(string: &str) -> Self {
-        let magnitude:i32 = string[1..].parse().unwrap();
-
-        match &string[0..1] {
-            "N" => Translate((0, 1), magnitude),
-            "S" => Translate((0, -1), magnitude),
-            "E" => Translate((1, 0), magnitude),
-            "W" => Translate((-1, 0), magnitude),
-            "L" => Rotate(magnitude),
-            "R" => Rotate(-magnitude),
-            "F" => Forward(magnitude),
-            _ => unreachable!()
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let action_length = string.chars().next().map_or(0, char::len_utf8);
+        let (action, magnitude) = string.split_at(action_length);
+
+        let magnitude: i32 = magnitude.parse()
+            .map_err(|_| ParseError::new(string, magnitude, "Invalid magnitude"))?;
+
+        match action {
+            "N" => Ok(Translate((0, 1), magnitude)),
+            "S" => Ok(Translate((0, -1), magnitude)),
+            "E" => Ok(Translate((1, 0), magnitude)),
+            "W" => Ok(Translate((-1, 0), magnitude)),
+            "L" => Ok(Rotate(magnitude)),
+            "R" => Ok(Rotate(-magnitude)),
+            "F" => Ok(Forward(magnitude)),
+            _ => Err(ParseError::new(string, action, "Unknown action")),
         }
     }
 }
@@ -121,18 +130,28 @@ impl Default for FerryPosition {
 
 #[cfg(test)]
 mod test {
-    use crate::navigation::{Instruction, FerryPosition};
-    use crate::navigation::Instruction::*;
+    use crate::day12::navigation::{Instruction, FerryPosition};
+    use crate::day12::navigation::Instruction::*;
+    use crate::parse::ParseError;
+    use std::str::FromStr;
 
     #[test]
     fn instruction_from() {
-        assert_eq!(Translate((0, 1), 4), Instruction::from("N4"));
-        assert_eq!(Translate((0, -1), 17), Instruction::from("S17"));
-        assert_eq!(Translate((1, 0), 99), Instruction::from("E99"));
-        assert_eq!(Translate((-1, 0), 3), Instruction::from("W3"));
-        assert_eq!(Rotate(270), Instruction::from("L270"));
-        assert_eq!(Rotate(-90), Instruction::from("R90"));
-        assert_eq!(Forward(10), Instruction::from("F10"));
+        assert_eq!(Translate((0, 1), 4), Instruction::from_str("N4").unwrap());
+        assert_eq!(Translate((0, -1), 17), Instruction::from_str("S17").unwrap());
+        assert_eq!(Translate((1, 0), 99), Instruction::from_str("E99").unwrap());
+        assert_eq!(Translate((-1, 0), 3), Instruction::from_str("W3").unwrap());
+        assert_eq!(Rotate(270), Instruction::from_str("L270").unwrap());
+        assert_eq!(Rotate(-90), Instruction::from_str("R90").unwrap());
+        assert_eq!(Forward(10), Instruction::from_str("F10").unwrap());
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("X"), message: String::from("Unknown action") }),
+                   Instruction::from_str("X10"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 2, text: String::from("ten"), message: String::from("Invalid magnitude") }),
+                   Instruction::from_str("Ften"));
+
+        assert!(Instruction::from_str("").is_err());
     }
 
     #[test]
@@ -220,8 +239,8 @@ mod test {
         }
 
         {
-            let instructions :Vec<Instruction> = vec!["F10", "N3", "F7", "R90", "F11"].iter()
-                .map(|string| Instruction::from(*string))
+            let instructions :Vec<Instruction> = ["F10", "N3", "F7", "R90", "F11"].iter()
+                .map(|string| Instruction::from_str(string).unwrap())
                 .collect();
 
             let mut position = FerryPosition::default();
@@ -239,8 +258,8 @@ mod test {
 
     #[test]
     fn ferry_position_apply_waypoint() {
-        let instructions :Vec<Instruction> = vec!["F10", "N3", "F7", "R90", "F11"].iter()
-            .map(|string| Instruction::from(*string))
+        let instructions :Vec<Instruction> = ["F10", "N3", "F7", "R90", "F11"].iter()
+            .map(|string| Instruction::from_str(string).unwrap())
             .collect();
 
         let mut position = FerryPosition {
@@ -14,14 +14,12 @@ impl BusSchedule {
         let mut earliest_arrival = 0xffffffff;
         let mut ealiest_route = 0;
 
-        for route in &self.routes {
-            if let Some(route) = route {
-                let next_arrival = Self::get_next_arrival(timestamp, *route);
+        for route in self.routes.iter().flatten() {
+            let next_arrival = Self::get_next_arrival(timestamp, *route);
 
-                if next_arrival < earliest_arrival {
-                    earliest_arrival = next_arrival;
-                    ealiest_route = *route;
-                }
+            if next_arrival < earliest_arrival {
+                earliest_arrival = next_arrival;
+                ealiest_route = *route;
             }
         }
 
@@ -32,7 +30,7 @@ impl BusSchedule {
     }
 
     fn get_next_arrival(timestamp: u64, route: u64) -> u64 {
-        if timestamp % route == 0 {
+        if timestamp.is_multiple_of(route) {
             timestamp
         } else {
             timestamp + route - (timestamp % route)
@@ -40,7 +38,7 @@ impl BusSchedule {
     }
 
     pub fn get_wait_time(timestamp: u64, route: u64) -> u64 {
-        if timestamp % route == 0 {
+        if timestamp.is_multiple_of(route) {
             0
         } else {
             route - (timestamp % route)
@@ -80,7 +78,7 @@ impl From<&str> for BusSchedule {
 
 #[cfg(test)]
 mod test {
-    use crate::bus::{BusSchedule, RouteRecommendation};
+    use crate::day13::bus::{BusSchedule, RouteRecommendation};
 
     #[test]
     fn bus_schedule_from_string() {
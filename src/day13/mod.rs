@@ -0,0 +1,31 @@
+use std::error;
+
+use itertools::Itertools;
+
+use crate::solution::{Answer, Solution};
+use crate::day13::bus::BusSchedule;
+
+pub mod bus;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (u64, BusSchedule);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let (timestamp, routes) = input.lines().collect_tuple()
+            .ok_or_else(|| simple_error::simple_error!("Expected a timestamp and a list of routes"))?;
+
+        Ok((timestamp.parse()?, BusSchedule::from(routes)))
+    }
+
+    fn part1(&self, (timestamp, schedule): &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let earliest_arrival = schedule.get_first_route_arriving_after_time(*timestamp);
+
+        Ok(Answer::from(BusSchedule::get_wait_time(*timestamp, earliest_arrival.route) * earliest_arrival.route))
+    }
+
+    fn part2(&self, (_, schedule): &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(schedule.get_alignment_timestamp()))
+    }
+}
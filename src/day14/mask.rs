@@ -1,5 +1,8 @@
 use std::collections::HashMap;
-use crate::mask::Instruction::{SetMask, SetValue};
+use std::str::FromStr;
+
+use crate::day14::mask::Instruction::{SetMask, SetValue};
+use crate::parse::ParseError;
 
 pub fn eval_value_mask<I>(instructions: I) -> u64
     where I: Iterator<Item = Instruction>
@@ -68,10 +71,10 @@ fn get_addresses(base_address: u64, mask: &Mask) -> Vec<u64> {
     for state in 0..(1 << mask.float.count_ones()) {
         let mut expanded_state: u64 = 0;
 
-        for i in 0..positions.len() {
+        for (i, position) in positions.iter().enumerate() {
             // Choose the ith bit (state & (1 << i)), then move it to its final position in the
             // expanded state value.
-            expanded_state |= (state & (1 << i)) << (positions[i] - i);
+            expanded_state |= (state & (1 << i)) << (position - i);
         }
 
         addresses.push(masked_address ^ expanded_state);
@@ -80,34 +83,38 @@ fn get_addresses(base_address: u64, mask: &Mask) -> Vec<u64> {
     addresses
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub enum Instruction {
     SetMask(Mask),
     SetValue(u64, u64),
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Mask {
     set: u64,
     clear: u64,
     float: u64,
 }
 
-impl From<&str> for Instruction {
-    fn from(line: &str) -> Self {
-        if line.starts_with("mask = ") {
-            SetMask(Mask::from(&line["mask = ".len()..]))
-        } else if line.starts_with("mem[") {
-            let open_bracket = line.find('[').unwrap();
-            let close_bracket = line.find(']').unwrap();
-            let equal_sign = line.find('=').unwrap();
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Some(mask) = line.strip_prefix("mask = ") {
+            Ok(SetMask(Mask::parse(line, mask)?))
+        } else if let Some(assignment) = line.strip_prefix("mem[") {
+            let (address, value) = assignment.split_once("] = ")
+                .ok_or_else(|| ParseError::new(line, assignment, "Expected an address and a value"))?;
 
-            let address: u64 = line[(open_bracket + 1)..close_bracket].parse().unwrap();
-            let value: u64 = line[(equal_sign + 1)..].trim().parse().unwrap();
+            let address: u64 = address.parse()
+                .map_err(|_| ParseError::new(line, address, "Invalid address"))?;
 
-            SetValue(address, value)
+            let value: u64 = value.parse()
+                .map_err(|_| ParseError::new(line, value, "Invalid value"))?;
+
+            Ok(SetValue(address, value))
         } else {
-            unreachable!()
+            Err(ParseError::new(line, line, "Expected a mask or a memory assignment"))
         }
     }
 }
@@ -116,26 +123,15 @@ impl Mask {
     fn apply(&self, value: u64) -> u64 {
         (value & self.clear) | self.set
     }
-}
-
-impl Default for Mask {
-    fn default() -> Self {
-        Mask {
-            set: 0,
-            clear: 0xffffffffffffffff,
-            float: 0,
-        }
-    }
-}
 
-impl From<&str> for Mask {
-
-    fn from(mask: &str) -> Self {
+    // Masks usually appear partway through an instruction, so errors are reported relative to the
+    // whole line
+    fn parse(line: &str, mask: &str) -> Result<Self, ParseError> {
         let mut set = 0;
         let mut clear = 0;
         let mut float = 0;
 
-        for c in mask.chars() {
+        for (i, c) in mask.char_indices() {
             set <<= 1;
             clear <<= 1;
             float <<= 1;
@@ -153,38 +149,67 @@ impl From<&str> for Mask {
                     float |= 1;
                 },
 
-                _ => {}
+                _ => return Err(ParseError::new(line, &mask[i..(i + c.len_utf8())], "Mask bits must be 0, 1 or X")),
             }
         }
 
-        Mask {
+        Ok(Mask {
             set,
             clear,
             float,
+        })
+    }
+}
+
+impl Default for Mask {
+    fn default() -> Self {
+        Mask {
+            set: 0,
+            clear: 0xffffffffffffffff,
+            float: 0,
         }
     }
 }
 
+impl FromStr for Mask {
+    type Err = ParseError;
+
+    fn from_str(mask: &str) -> Result<Self, Self::Err> {
+        Mask::parse(mask, mask)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::mask::{Mask, Instruction};
-    use crate::mask::Instruction::{SetMask, SetValue};
-    use crate::mask;
+    use crate::day14::mask::{Mask, Instruction};
+    use crate::day14::mask::Instruction::{SetMask, SetValue};
+    use crate::day14::mask;
+    use crate::parse::ParseError;
     use std::collections::HashSet;
+    use std::str::FromStr;
 
     #[test]
     fn instruction_from_string() {
-        assert_eq!(SetMask(Mask::from("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X")),
-                   Instruction::from("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"));
+        assert_eq!(SetMask(Mask::from_str("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap()),
+                   Instruction::from_str("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap());
 
         assert_eq!(SetValue(8, 11),
-                   Instruction::from("mem[8] = 11"));
+                   Instruction::from_str("mem[8] = 11").unwrap());
 
         assert_eq!(SetValue(7, 101),
-                   Instruction::from("mem[7] = 101"));
+                   Instruction::from_str("mem[7] = 101").unwrap());
 
         assert_eq!(SetValue(1234, 567890),
-                   Instruction::from("mem[1234] = 567890"));
+                   Instruction::from_str("mem[1234] = 567890").unwrap());
+
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from("x"), message: String::from("Invalid address") }),
+                   Instruction::from_str("mem[x] = 11"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("mem 8 = 11"), message: String::from("Expected a mask or a memory assignment") }),
+                   Instruction::from_str("mem 8 = 11"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 11, text: String::from("2"), message: String::from("Mask bits must be 0, 1 or X") }),
+                   Instruction::from_str("mask = XXX2"));
     }
 
     #[test]
@@ -195,12 +220,12 @@ mod test {
             float: 0b111111111111111111111111111110111101,
         };
 
-        assert_eq!(expected, Mask::from("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"));
+        assert_eq!(expected, Mask::from_str("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap());
     }
 
     #[test]
     fn mask_apply() {
-        let mask = Mask::from("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X");
+        let mask = Mask::from_str("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap();
 
         assert_eq!(73, mask.apply(11));
         assert_eq!(101, mask.apply(101));
@@ -210,11 +235,11 @@ mod test {
     #[test]
     fn eval() {
         let instructions: Vec<Instruction> =
-            vec!["mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+            ["mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
                  "mem[8] = 11",
                  "mem[7] = 101",
-                 "mem[8] = 0",].iter()
-                .map(|line| Instruction::from(*line))
+                 "mem[8] = 0"].iter()
+                .map(|line| Instruction::from_str(line).unwrap())
                 .collect();
 
         assert_eq!(165, mask::eval_value_mask(instructions.into_iter()));
@@ -223,7 +248,7 @@ mod test {
     #[test]
     fn get_addresses() {
         {
-            let mask = Mask::from("000000000000000000000000000000X1001X");
+            let mask = Mask::from_str("000000000000000000000000000000X1001X").unwrap();
             let address = 42;
 
             let expected: HashSet<u64> = vec![26, 27, 58, 59].into_iter().collect();
@@ -231,7 +256,7 @@ mod test {
         }
 
         {
-            let mask = Mask::from("00000000000000000000000000000000X0XX");
+            let mask = Mask::from_str("00000000000000000000000000000000X0XX").unwrap();
             let address = 26;
 
             let expected: HashSet<u64> = vec![16, 17, 18, 19, 24, 25, 26, 27].into_iter().collect();
@@ -242,11 +267,11 @@ mod test {
     #[test]
     fn eval_address_mask() {
         let instructions: Vec<Instruction> =
-            vec!["mask = 000000000000000000000000000000X1001X",
+            ["mask = 000000000000000000000000000000X1001X",
                  "mem[42] = 100",
                  "mask = 00000000000000000000000000000000X0XX",
-                 "mem[26] = 1",].iter()
-                .map(|line| Instruction::from(*line))
+                 "mem[26] = 1"].iter()
+                .map(|line| Instruction::from_str(line).unwrap())
                 .collect();
 
         assert_eq!(208, mask::eval_address_mask(instructions.into_iter()));
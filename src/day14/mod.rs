@@ -0,0 +1,25 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day14::mask::Instruction;
+use crate::parse;
+
+pub mod mask;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(mask::eval_value_mask(instructions.iter().cloned())))
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(mask::eval_address_mask(instructions.iter().cloned())))
+    }
+}
@@ -0,0 +1,31 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+
+pub mod count;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let initial_values: Vec<u64> = input.trim().split(',')
+            .map(|value| value.parse())
+            .collect::<Result<_, _>>()?;
+
+        if initial_values.is_empty() {
+            simple_error::bail!("No initial values in input");
+        }
+
+        Ok(initial_values)
+    }
+
+    fn part1(&self, initial_values: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(count::play_game(initial_values, 2020)))
+    }
+
+    fn part2(&self, initial_values: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(count::play_game(initial_values, 30000000)))
+    }
+}
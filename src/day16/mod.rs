@@ -0,0 +1,56 @@
+use std::error;
+
+use itertools::Itertools;
+
+use crate::solution::{Answer, Solution};
+use crate::day16::ticket::{Field, Ticket};
+use crate::parse;
+
+pub mod ticket;
+
+pub struct Day16;
+
+pub struct Notes {
+    fields: Vec<Field>,
+    my_ticket: Ticket,
+    tickets: Vec<Ticket>,
+}
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let ((_, fields), (my_ticket_start, my_ticket), (tickets_start, tickets)) = parse::sections(input).into_iter()
+            .filter(|(_, section)| !section.is_empty())
+            .collect_tuple()
+            .ok_or_else(|| simple_error::simple_error!("Expected sections for fields, my ticket and nearby tickets"))?;
+
+        let fields = parse::parse_section(fields, 0)?;
+
+        // Skip the "your ticket:" and "nearby tickets:" headers
+        let my_ticket = parse::parse_section(section_body(my_ticket), my_ticket_start + 1)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| simple_error::simple_error!("No values for my ticket"))?;
+
+        let tickets = parse::parse_section(section_body(tickets), tickets_start + 1)?;
+
+        Ok(Notes { fields, my_ticket, tickets })
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(ticket::get_error_rate(&notes.tickets, &notes.fields)))
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let product: u64 = ticket::get_departure_field_indices(&notes.tickets, &notes.fields).iter()
+            .map(|i| notes.my_ticket.values[*i] as u64)
+            .product();
+
+        Ok(Answer::from(product))
+    }
+}
+
+fn section_body(section: &str) -> &str {
+    section.split_once('\n').map_or("", |(_, body)| body)
+}
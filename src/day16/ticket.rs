@@ -0,0 +1,269 @@
+use regex::Regex;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Field {
+    name: String,
+    ranges: [(u32, u32); 2],
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Ticket {
+    pub values: Vec<u32>,
+}
+
+pub fn get_error_rate(tickets: &[Ticket], fields: &[Field]) -> u32 {
+    tickets.iter()
+        .map(|ticket| ticket.get_invalid_values(fields).iter().sum::<u32>())
+        .sum()
+}
+
+fn get_candidate_fields<'a>(tickets: &[Ticket], fields: &'a [Field]) -> Vec<HashSet<&'a Field>> {
+    let valid_tickets: Vec<&Ticket> = tickets.iter()
+        .filter(|ticket| ticket.is_valid(fields))
+        .collect();
+
+    let mut candidates_by_position = Vec::new();
+
+    for i in 0..valid_tickets[0].values.len() {
+        let values_for_position: Vec<u32> = valid_tickets.iter()
+            .map(|ticket| ticket.values[i])
+            .collect();
+
+        candidates_by_position.push(fields.iter()
+            .filter(|field| values_for_position.iter().all(|value| field.is_valid(*value)))
+            .collect());
+    }
+
+    candidates_by_position
+}
+
+fn get_field_order<'a>(tickets: &[Ticket], fields: &'a [Field]) -> Vec<&'a Field> {
+    let mut candidates_by_position = get_candidate_fields(tickets, fields);
+
+    while candidates_by_position.iter().any(|field_set| field_set.len() > 1) {
+        let mut confirmed_fields = Vec::new();
+
+        candidates_by_position.iter()
+            .filter(|field_set| field_set.len() == 1)
+            .for_each(|single_field_set| {
+                confirmed_fields.push(*single_field_set.iter().next().unwrap());
+            });
+
+        candidates_by_position.iter_mut()
+            .filter(|field_set| field_set.len() > 1)
+            .for_each(|field_set| {
+                confirmed_fields.iter().for_each(|field| {
+                    field_set.remove(field);
+                });
+            })
+    }
+
+    candidates_by_position.into_iter()
+        .map(|field_set| *field_set.iter().next().unwrap())
+        .collect()
+}
+
+pub fn get_departure_field_indices(tickets: &[Ticket], fields: &[Field]) -> Vec<usize> {
+    let mut indices = Vec::new();
+    let ordered_fields = get_field_order(tickets, fields);
+
+    for (i, field) in ordered_fields.iter().enumerate() {
+        if field.is_departure_field() {
+            indices.push(i);
+        }
+    }
+
+    indices
+}
+
+impl FromStr for Field {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref FIELD_RE: Regex = Regex::new("^([a-z ]+):\\s*([0-9]+)-([0-9]+) or ([0-9]+)-([0-9]+)$").unwrap();
+        }
+
+        let captures = FIELD_RE.captures(line)
+            .ok_or_else(|| ParseError::new(line, line, "Expected a field name and two ranges"))?;
+
+        let bound = |i: usize| -> Result<u32, ParseError> {
+            let text = captures.get(i).unwrap().as_str();
+            text.parse().map_err(|_| ParseError::new(line, text, "Invalid bound"))
+        };
+
+        Ok(Field {
+            name: String::from(&captures[1]),
+            ranges: [(bound(2)?, bound(3)?), (bound(4)?, bound(5)?)],
+        })
+    }
+}
+
+impl FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let values = line.split(',')
+            .map(|number| number.parse().map_err(|_| ParseError::new(line, number, "Invalid value")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Ticket { values })
+    }
+}
+
+impl Field {
+    pub fn is_valid(&self, value: u32) -> bool {
+        (value >= self.ranges[0].0 && value <= self.ranges[0].1) ||
+            (value >= self.ranges[1].0 && value <= self.ranges[1].1)
+    }
+
+    pub fn is_departure_field(&self) -> bool {
+        self.name.starts_with("departure")
+    }
+}
+
+impl Ticket {
+    pub fn get_invalid_values(&self, fields: &[Field]) -> Vec<u32> {
+        self.values.iter()
+            .filter(|value| fields.iter().all(|field| !field.is_valid(**value)))
+            .copied()
+            .collect()
+    }
+
+    pub fn is_valid(&self, fields: &[Field]) -> bool {
+        self.get_invalid_values(fields).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day16::ticket::{Field, Ticket};
+    use crate::day16::ticket;
+    use crate::parse::ParseError;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    #[test]
+    fn field_from_str() {
+        let expected = Field {
+            name: String::from("row"),
+            ranges: [(6, 11), (33, 44)],
+        };
+
+        assert_eq!(expected, Field::from_str("row: 6-11 or 33-44").unwrap());
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("row 6-11 or 33-44"), message: String::from("Expected a field name and two ranges") }),
+                   Field::from_str("row 6-11 or 33-44"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 14, text: String::from("99999999999"), message: String::from("Invalid bound") }),
+                   Field::from_str("row: 6-11 or 99999999999-44"));
+    }
+
+    #[test]
+    fn ticket_from_str() {
+        let expected = Ticket {
+            values: vec![7, 1, 14],
+        };
+
+        assert_eq!(expected, Ticket::from_str("7,1,14").unwrap());
+
+        assert_eq!(Err(ParseError { line: 1, column: 3, text: String::from(""), message: String::from("Invalid value") }),
+                   Ticket::from_str("7,,14"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from("x4"), message: String::from("Invalid value") }),
+                   Ticket::from_str("7,1,x4"));
+    }
+
+    #[test]
+    fn field_is_valid() {
+        let field = Field::from_str("class: 1-3 or 5-7").unwrap();
+
+        assert!(field.is_valid(3));
+        assert!(field.is_valid(5));
+        assert!(!field.is_valid(4));
+    }
+
+    #[test]
+    fn ticket_get_invalid_values() {
+        let fields = vec![Field::from_str("class: 1-3 or 5-7").unwrap(),
+                          Field::from_str("row: 6-11 or 33-44").unwrap(),
+                          Field::from_str("seat: 13-40 or 45-50").unwrap(),];
+
+        let empty: Vec<u32> = vec![];
+
+        assert_eq!(empty, Ticket::from_str("7,3,47").unwrap().get_invalid_values(&fields));
+        assert_eq!(vec![4], Ticket::from_str("40,4,50").unwrap().get_invalid_values(&fields));
+        assert_eq!(vec![55], Ticket::from_str("55,2,20").unwrap().get_invalid_values(&fields));
+        assert_eq!(vec![12], Ticket::from_str("38,6,12").unwrap().get_invalid_values(&fields));
+    }
+
+    #[test]
+    fn get_error_rate() {
+        let fields = vec![Field::from_str("class: 1-3 or 5-7").unwrap(),
+                          Field::from_str("row: 6-11 or 33-44").unwrap(),
+                          Field::from_str("seat: 13-40 or 45-50").unwrap(),];
+
+        let tickets = vec![Ticket::from_str("7,3,47").unwrap(),
+                           Ticket::from_str("40,4,50").unwrap(),
+                           Ticket::from_str("55,2,20").unwrap(),
+                           Ticket::from_str("38,6,12").unwrap(),];
+
+        assert_eq!(71, ticket::get_error_rate(&tickets, &fields));
+    }
+
+    #[test]
+    fn ticket_is_valid() {
+        let fields = vec![Field::from_str("class: 1-3 or 5-7").unwrap(),
+                          Field::from_str("row: 6-11 or 33-44").unwrap(),
+                          Field::from_str("seat: 13-40 or 45-50").unwrap(),];
+
+        assert!(Ticket::from_str("7,3,47").unwrap().is_valid(&fields));
+        assert!(!Ticket::from_str("40,4,50").unwrap().is_valid(&fields));
+        assert!(!Ticket::from_str("55,2,20").unwrap().is_valid(&fields));
+        assert!(!Ticket::from_str("38,6,12").unwrap().is_valid(&fields));
+    }
+
+    #[test]
+    fn get_candidate_fields() {
+        let fields = vec![Field::from_str("class: 0-1 or 4-19").unwrap(),
+                          Field::from_str("row: 0-5 or 8-19").unwrap(),
+                          Field::from_str("seat: 0-13 or 16-19").unwrap(),];
+
+        let tickets = vec![Ticket::from_str("3,9,18").unwrap(),
+                           Ticket::from_str("15,1,5").unwrap(),
+                           Ticket::from_str("5,14,9").unwrap(),];
+
+        let mut expected = vec![HashSet::new(), HashSet::new(), HashSet::new()];
+        expected[0].insert(&fields[1]);
+        expected[1].insert(&fields[0]);
+        expected[1].insert(&fields[1]);
+        expected[2].insert(&fields[0]);
+        expected[2].insert(&fields[1]);
+        expected[2].insert(&fields[2]);
+
+        assert_eq!(expected, ticket::get_candidate_fields(&tickets, &fields));
+    }
+
+    #[test]
+    fn get_field_order() {
+        let fields = vec![Field::from_str("class: 0-1 or 4-19").unwrap(),
+                          Field::from_str("row: 0-5 or 8-19").unwrap(),
+                          Field::from_str("seat: 0-13 or 16-19").unwrap(),];
+
+        let tickets = vec![Ticket::from_str("3,9,18").unwrap(),
+                           Ticket::from_str("15,1,5").unwrap(),
+                           Ticket::from_str("5,14,9").unwrap(),];
+
+        assert_eq!(vec![&fields[1], &fields[0], &fields[2]], ticket::get_field_order(&tickets, &fields));
+    }
+
+    #[test]
+    fn field_is_departure_field() {
+        assert!(Field::from_str("departure time: 31-660 or 678-951").unwrap().is_departure_field());
+        assert!(!Field::from_str("arrival location: 26-482 or 504-959").unwrap().is_departure_field());
+    }
+}
\ No newline at end of file
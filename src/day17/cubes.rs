@@ -4,7 +4,7 @@ use std::cmp::{min, max};
 use std::fmt;
 use std::fmt::Formatter;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct PocketDimension {
     cells: HashSet<(isize, isize, isize, isize)>,
 }
@@ -76,7 +76,7 @@ impl PocketDimension {
         }
     }
 
-    fn into_next_round_hypercube(self) -> Self {
+    pub fn into_next_round_hypercube(self) -> Self {
         let mut cells = HashSet::new();
         let bounds = self.get_bounds();
 
@@ -151,7 +151,7 @@ impl fmt::Display for PocketDimension {
 
         for w in bounds.clone().3 {
             for z in bounds.clone().2 {
-                write!(f, "z={}, w={}\n", z, w)?;
+                writeln!(f, "z={}, w={}", z, w)?;
 
                 for y in bounds.clone().1.rev() {
                     for x in bounds.clone().0 {
@@ -164,10 +164,10 @@ impl fmt::Display for PocketDimension {
                         write!(f, "{}", c)?;
                     }
 
-                    write!(f, "\n")?;
+                    writeln!(f)?;
                 }
 
-                write!(f, "\n")?;
+                writeln!(f)?;
             }
         }
 
@@ -177,7 +177,7 @@ impl fmt::Display for PocketDimension {
 
 #[cfg(test)]
 mod test {
-    use crate::cubes::PocketDimension;
+    use crate::day17::cubes::PocketDimension;
     use std::collections::HashSet;
 
     #[test]
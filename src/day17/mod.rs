@@ -0,0 +1,24 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day17::cubes::PocketDimension;
+
+pub mod cubes;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = PocketDimension;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(PocketDimension::from(String::from(input)))
+    }
+
+    fn part1(&self, pocket_dimension: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(pocket_dimension.clone().get_active_cells_after_rounds(6)))
+    }
+
+    fn part2(&self, pocket_dimension: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(pocket_dimension.clone().get_active_cells_after_rounds_hypercube(6)))
+    }
+}
@@ -0,0 +1,224 @@
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Token {
+    Number(u64),
+    Add,
+    Multiply,
+    Open,
+    Close,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Precedence {
+    // Operators are applied strictly left to right
+    Equal,
+
+    // Addition binds more tightly than multiplication
+    AdditionFirst,
+}
+
+impl Precedence {
+    fn binding_power(&self, operator: Token) -> u8 {
+        match (self, operator) {
+            (Precedence::AdditionFirst, Token::Add) => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// A well-formed expression; parsing checks that numbers and operators alternate and that
+/// parentheses match, so evaluating only fails if the result doesn't fit in a `u64`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Expression {
+    tokens: Vec<Token>,
+}
+
+impl Expression {
+    /// Evaluates the expression, or returns `None` if any step overflows.
+    pub fn evaluate(&self, precedence: Precedence) -> Option<u64> {
+        let mut position = 0;
+        self.evaluate_from(&mut position, 0, precedence)
+    }
+
+    // Evaluates operators from the current position until reaching an operator that binds less
+    // tightly than `min_power` (or the end of a parenthesized group)
+    fn evaluate_from(&self, position: &mut usize, min_power: u8, precedence: Precedence) -> Option<u64> {
+        let mut value = self.evaluate_operand(position, precedence)?;
+
+        while let Some(operator) = self.tokens.get(*position).copied() {
+            if operator == Token::Close || precedence.binding_power(operator) < min_power {
+                break;
+            }
+
+            *position += 1;
+
+            // Operators are left-associative, so the right-hand side only takes operators that bind
+            // strictly more tightly
+            let rhs = self.evaluate_from(position, precedence.binding_power(operator) + 1, precedence)?;
+
+            value = match operator {
+                Token::Add => value.checked_add(rhs)?,
+                Token::Multiply => value.checked_mul(rhs)?,
+                _ => unreachable!("Parsing guarantees an operator follows an operand"),
+            };
+        }
+
+        Some(value)
+    }
+
+    fn evaluate_operand(&self, position: &mut usize, precedence: Precedence) -> Option<u64> {
+        let token = self.tokens[*position];
+        *position += 1;
+
+        match token {
+            Token::Number(n) => Some(n),
+            Token::Open => {
+                let value = self.evaluate_from(position, 0, precedence);
+
+                // Skip the closing parenthesis
+                *position += 1;
+
+                value
+            },
+            _ => unreachable!("Parsing guarantees an expression starts with a number or '('"),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Vec::new();
+        let mut open_positions = Vec::new();
+        let mut expecting_operand = true;
+
+        let mut chars = line.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let token = match c {
+                ' ' => continue,
+                '0'..='9' => {
+                    let mut end = i + 1;
+
+                    while let Some((j, '0'..='9')) = chars.peek().copied() {
+                        end = j + 1;
+                        chars.next();
+                    }
+
+                    let digits = &line[i..end];
+
+                    Token::Number(digits.parse().map_err(|_| ParseError::new(line, digits, "Number is too large"))?)
+                },
+                '+' => Token::Add,
+                '*' => Token::Multiply,
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ => return Err(ParseError::new(line, &line[i..(i + c.len_utf8())], "Unexpected character")),
+            };
+
+            let text = &line[i..(i + c.len_utf8())];
+
+            match token {
+                Token::Number(_) | Token::Open if !expecting_operand => {
+                    return Err(ParseError::new(line, text, "Expected an operator or ')'"));
+                },
+                Token::Add | Token::Multiply | Token::Close if expecting_operand => {
+                    return Err(ParseError::new(line, text, "Expected a number or '('"));
+                },
+                Token::Number(_) => expecting_operand = false,
+                Token::Add | Token::Multiply => expecting_operand = true,
+                Token::Open => open_positions.push(i),
+                Token::Close => {
+                    if open_positions.pop().is_none() {
+                        return Err(ParseError::new(line, text, "Unmatched ')'"));
+                    }
+                },
+            }
+
+            tokens.push(token);
+        }
+
+        if let Some(open) = open_positions.pop() {
+            Err(ParseError::new(line, &line[open..(open + 1)], "Unclosed '('"))
+        } else if expecting_operand {
+            Err(ParseError::new(line, &line[line.len()..], "Expected a number or '('"))
+        } else {
+            Ok(Expression { tokens })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day18::expression::{Expression, Precedence};
+    use crate::parse::ParseError;
+
+    fn evaluate(expression: &str, precedence: Precedence) -> u64 {
+        expression.parse::<Expression>().unwrap().evaluate(precedence).unwrap()
+    }
+
+    #[test]
+    fn evaluate_equal_precedence() {
+        assert_eq!(71, evaluate("1 + 2 * 3 + 4 * 5 + 6", Precedence::Equal));
+        assert_eq!(51, evaluate("1 + (2 * 3) + (4 * (5 + 6))", Precedence::Equal));
+        assert_eq!(26, evaluate("2 * 3 + (4 * 5)", Precedence::Equal));
+        assert_eq!(437, evaluate("5 + (8 * 3 + 9 + 3 * 4 * 3)", Precedence::Equal));
+        assert_eq!(12240, evaluate("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", Precedence::Equal));
+        assert_eq!(13632, evaluate("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", Precedence::Equal));
+    }
+
+    #[test]
+    fn evaluate_addition_first() {
+        assert_eq!(231, evaluate("1 + 2 * 3 + 4 * 5 + 6", Precedence::AdditionFirst));
+        assert_eq!(51, evaluate("1 + (2 * 3) + (4 * (5 + 6))", Precedence::AdditionFirst));
+        assert_eq!(46, evaluate("2 * 3 + (4 * 5)", Precedence::AdditionFirst));
+        assert_eq!(1445, evaluate("5 + (8 * 3 + 9 + 3 * 4 * 3)", Precedence::AdditionFirst));
+        assert_eq!(669060, evaluate("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", Precedence::AdditionFirst));
+        assert_eq!(23340, evaluate("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", Precedence::AdditionFirst));
+    }
+
+    #[test]
+    fn evaluate_multi_digit_numbers() {
+        assert_eq!(1234, evaluate("1000 + (200 + 34)", Precedence::Equal));
+        assert_eq!(7, evaluate("(((7)))", Precedence::AdditionFirst));
+    }
+
+    #[test]
+    fn evaluate_overflow() {
+        let expression: Expression = "99999999999 * 99999999999".parse().unwrap();
+        assert_eq!(None, expression.evaluate(Precedence::Equal));
+
+        // Whether this overflows depends on which operator goes first
+        let expression: Expression = "0 * 18446744073709551615 + 1".parse().unwrap();
+        assert_eq!(Some(1), expression.evaluate(Precedence::Equal));
+        assert_eq!(None, expression.evaluate(Precedence::AdditionFirst));
+    }
+
+    #[test]
+    fn expression_from_str_errors() {
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from("*"), message: String::from("Expected a number or '('") }),
+                   "1 + * 2".parse::<Expression>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 3, text: String::from("("), message: String::from("Expected an operator or ')'") }),
+                   "2 (3)".parse::<Expression>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 6, text: String::from(")"), message: String::from("Unmatched ')'") }),
+                   "1 + 2) * 3".parse::<Expression>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from("("), message: String::from("Unclosed '('") }),
+                   "1 + (2 * 3".parse::<Expression>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from("-"), message: String::from("Unexpected character") }),
+                   "1 + -2".parse::<Expression>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 4, text: String::from(""), message: String::from("Expected a number or '('") }),
+                   "1 +".parse::<Expression>());
+
+        assert!("".parse::<Expression>().is_err());
+        assert!("()".parse::<Expression>().is_err());
+    }
+}
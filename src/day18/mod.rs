@@ -0,0 +1,37 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day18::expression::{Expression, Precedence};
+use crate::parse;
+
+pub mod expression;
+
+pub struct Day18;
+
+impl Day18 {
+    fn sum(expressions: &[Expression], precedence: Precedence) -> Result<u64, Box<dyn error::Error>> {
+        expressions.iter()
+            .enumerate()
+            .try_fold(0u64, |sum, (i, expression)| {
+                expression.evaluate(precedence)
+                    .and_then(|value| sum.checked_add(value))
+                    .ok_or_else(|| simple_error::simple_error!("Overflow evaluating line {}", i + 1).into())
+            })
+    }
+}
+
+impl Solution for Day18 {
+    type Input = Vec<Expression>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part1(&self, expressions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(Day18::sum(expressions, Precedence::Equal)?))
+    }
+
+    fn part2(&self, expressions: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(Day18::sum(expressions, Precedence::AdditionFirst)?))
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::{HashMap, HashSet};
+use std::error;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::day19::message::{Rule, RuleSet};
+
+// The longest pattern to build when unrolling recursive rules. Rules that repeat in more than one
+// place (like `0: 0 0 | 1`) can double in size with every extra byte they cover.
+const MAX_PATTERN_LENGTH: usize = 1 << 17;
+
+/// A rule compiled down to a regular expression, for checking lots of messages against the same
+/// rule. Recursive rules aren't regular, so they get unrolled just far enough to cover messages up
+/// to a given length; anything longer falls back to the backtracking matcher.
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    rule_set: RuleSet,
+    rule_id: usize,
+    max_length: Option<usize>,
+
+    // `None` if the rule can't match anything (up to `max_length`, if that's set)
+    regex: Option<Regex>,
+}
+
+impl CompiledRule {
+    pub fn matches(&self, message: &str) -> bool {
+        match self.max_length {
+            Some(max_length) if message.len() > max_length => self.rule_set.matches(self.rule_id, message),
+            _ => self.regex.as_ref().is_some_and(|regex| regex.is_match(message)),
+        }
+    }
+}
+
+impl RuleSet {
+    /// Writes out the given rule as an anchored regular expression. Only works if the rule doesn't
+    /// depend on itself, directly or through other rules.
+    pub fn to_regex(&self, rule_id: usize) -> Result<String, Box<dyn error::Error>> {
+        let mut compiler = Compiler::new(self);
+
+        if let Some(recursive) = compiler.reachable(rule_id).into_iter().find(|id| compiler.is_recursive(*id)) {
+            simple_error::bail!("Rule {} is recursive, so it can't be written as a regular expression", recursive);
+        }
+
+        let pattern = compiler.pattern(rule_id)
+            .ok_or_else(|| simple_error::simple_error!("Rule {} can't match any message", rule_id))?;
+
+        Ok(format!("^(?:{})$", pattern))
+    }
+
+    /// Compiles the given rule for matching in bulk. Recursive rules are unrolled to cover messages
+    /// up to `max_length` bytes long, which is usually the length of the longest message to check.
+    /// If that would take too big a pattern, they cover shorter messages instead, and the rest are
+    /// left to the backtracking matcher.
+    pub fn compile(&self, rule_id: usize, max_length: usize) -> Result<CompiledRule, Box<dyn error::Error>> {
+        let mut compiler = Compiler::new(self);
+        let recursive = compiler.reachable(rule_id).into_iter().any(|id| compiler.is_recursive(id));
+        let mut max_length = max_length;
+
+        let pattern = if recursive {
+            loop {
+                compiler.too_large = false;
+                let pattern = compiler.bounded_pattern(rule_id, max_length);
+
+                if !compiler.too_large || max_length == 0 {
+                    break pattern;
+                }
+
+                max_length -= 1;
+            }
+        } else {
+            compiler.pattern(rule_id)
+        };
+
+        // Unrolled rules can get big, so allow for a bigger compiled program than usual
+        let regex = pattern
+            .map(|pattern| RegexBuilder::new(&format!("^(?:{})$", pattern)).size_limit(1 << 28).build())
+            .transpose()?;
+
+        Ok(CompiledRule {
+            rule_set: self.clone(),
+            rule_id,
+            max_length: if recursive { Some(max_length) } else { None },
+            regex,
+        })
+    }
+}
+
+struct Compiler<'a> {
+    rules: &'a [Vec<Rule>],
+
+    // The length of the shortest message each rule can match, or `None` if it can't match anything
+    min_lengths: Vec<Option<usize>>,
+
+    // Whether each rule can refer back to itself
+    recursive: Vec<bool>,
+
+    patterns: HashMap<usize, Option<String>>,
+    bounded_patterns: HashMap<(usize, usize), Option<String>>,
+    in_progress: HashSet<(usize, usize)>,
+
+    // How many bounded patterns have been cut short, either by a loop or by running into
+    // `MAX_PATTERN_LENGTH`; patterns built from those aren't complete, so they aren't cached
+    cut_short: usize,
+    too_large: bool,
+}
+
+impl<'a> Compiler<'a> {
+    fn new(rule_set: &'a RuleSet) -> Self {
+        let rules = rule_set.rules.as_slice();
+
+        let mut compiler = Compiler {
+            rules,
+            min_lengths: vec![None; rules.len()],
+            recursive: vec![false; rules.len()],
+            patterns: HashMap::new(),
+            bounded_patterns: HashMap::new(),
+            in_progress: HashSet::new(),
+            cut_short: 0,
+            too_large: false,
+        };
+
+        compiler.find_min_lengths();
+
+        compiler.recursive = (0..rules.len())
+            .map(|id| compiler.sub_rules(id).any(|sub_rule| compiler.reachable(sub_rule).contains(&id)))
+            .collect();
+
+        compiler
+    }
+
+    fn alternatives(&self, rule_id: usize) -> &'a [Rule] {
+        self.rules.get(rule_id).map_or(&[], Vec::as_slice)
+    }
+
+    fn sub_rules(&self, rule_id: usize) -> impl Iterator<Item = usize> + 'a {
+        self.alternatives(rule_id).iter()
+            .flat_map(|alternative| match alternative {
+                Rule::SubRules(sub_rules) => sub_rules.as_slice(),
+                Rule::Literal(_) => &[],
+            })
+            .copied()
+    }
+
+    fn is_recursive(&self, rule_id: usize) -> bool {
+        self.recursive.get(rule_id).copied().unwrap_or(false)
+    }
+
+    fn min_length(&self, rule_id: usize) -> Option<usize> {
+        self.min_lengths.get(rule_id).copied().flatten()
+    }
+
+    // Keeps shortening the shortest known match for each rule until nothing changes
+    fn find_min_lengths(&mut self) {
+        loop {
+            let mut changed = false;
+
+            for id in 0..self.rules.len() {
+                let min_length = self.alternatives(id).iter()
+                    .filter_map(|alternative| match alternative {
+                        Rule::Literal(c) => Some(c.len_utf8()),
+                        Rule::SubRules(sub_rules) => sub_rules.iter().map(|sub_rule| self.min_length(*sub_rule)).sum(),
+                    })
+                    .min();
+
+                if min_length.is_some() && (self.min_lengths[id].is_none() || min_length < self.min_lengths[id]) {
+                    self.min_lengths[id] = min_length;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    // Every rule the given rule depends on, including itself
+    fn reachable(&self, rule_id: usize) -> HashSet<usize> {
+        let mut reachable = HashSet::new();
+        let mut pending = vec![rule_id];
+
+        while let Some(id) = pending.pop() {
+            if reachable.insert(id) {
+                pending.extend(self.sub_rules(id));
+            }
+        }
+
+        reachable
+    }
+
+    fn join(alternatives: Vec<String>) -> Option<String> {
+        match alternatives.len() {
+            0 => None,
+            1 => alternatives.into_iter().next(),
+            _ => Some(format!("(?:{})", alternatives.join("|"))),
+        }
+    }
+
+    // The pattern for a rule that isn't recursive, which is the same wherever the rule appears
+    fn pattern(&mut self, rule_id: usize) -> Option<String> {
+        if let Some(pattern) = self.patterns.get(&rule_id) {
+            return pattern.clone();
+        }
+
+        let mut alternatives = Vec::new();
+
+        for alternative in self.alternatives(rule_id) {
+            let pattern = match alternative {
+                Rule::Literal(c) => Some(regex::escape(&c.to_string())),
+                Rule::SubRules(sub_rules) => sub_rules.iter()
+                    .map(|sub_rule| self.pattern(*sub_rule))
+                    .collect::<Option<String>>(),
+            };
+
+            alternatives.extend(pattern);
+        }
+
+        let pattern = Compiler::join(alternatives);
+        self.patterns.insert(rule_id, pattern.clone());
+
+        pattern
+    }
+
+    // The pattern for a rule that can only use up to `budget` bytes of the message; recursive rules
+    // stop repeating once there's no room left for another repetition
+    fn bounded_pattern(&mut self, rule_id: usize, budget: usize) -> Option<String> {
+        if self.min_length(rule_id)? > budget {
+            return None;
+        }
+
+        let key = (rule_id, budget);
+
+        if let Some(pattern) = self.bounded_patterns.get(&key) {
+            return pattern.clone();
+        }
+
+        if !self.reachable(rule_id).into_iter().any(|id| self.is_recursive(id)) {
+            return self.pattern(rule_id);
+        }
+
+        // Coming back to the same rule with the same budget means it repeated without matching
+        // anything, and would never stop
+        if !self.in_progress.insert(key) {
+            self.cut_short += 1;
+            return None;
+        }
+
+        let cut_short = self.cut_short;
+
+        let mut alternatives = Vec::new();
+
+        for alternative in self.alternatives(rule_id) {
+            let pattern = match alternative {
+                Rule::Literal(c) => Some(regex::escape(&c.to_string())),
+                Rule::SubRules(sub_rules) => {
+                    // Each sub-rule gets whatever's left after the shortest possible matches for the
+                    // others
+                    let total: Option<usize> = sub_rules.iter().map(|sub_rule| self.min_length(*sub_rule)).sum();
+
+                    total.filter(|total| *total <= budget).and_then(|total| {
+                        sub_rules.iter()
+                            .map(|sub_rule| {
+                                let others = total - self.min_length(*sub_rule).unwrap_or(0);
+                                self.bounded_pattern(*sub_rule, budget - others)
+                            })
+                            .collect::<Option<String>>()
+                    })
+                },
+            };
+
+            alternatives.extend(pattern);
+
+            if self.too_large {
+                break;
+            }
+        }
+
+        self.in_progress.remove(&key);
+
+        let pattern = Compiler::join(alternatives);
+
+        if self.too_large || pattern.as_ref().is_some_and(|pattern| pattern.len() > MAX_PATTERN_LENGTH) {
+            self.too_large = true;
+            self.cut_short += 1;
+            return None;
+        }
+
+        if self.cut_short == cut_short {
+            self.bounded_patterns.insert(key, pattern.clone());
+        }
+
+        pattern
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day19::message::RuleSet;
+
+    const RULES: &str = "0: 4 1 5\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n";
+
+    const LOOPING_RULES: &str = "0: 8 11\n8: 42 | 42 8\n11: 42 31 | 42 11 31\n42: \"a\"\n31: \"b\"\n";
+
+    #[test]
+    fn to_regex() {
+        let rules: RuleSet = RULES.parse().unwrap();
+
+        assert_eq!("^(?:a(?:(?:aa|bb)(?:ab|ba)|(?:ab|ba)(?:aa|bb))b)$", rules.to_regex(0).unwrap());
+        assert_eq!("^(?:a)$", rules.to_regex(4).unwrap());
+
+        assert!(rules.to_regex(6).is_err());
+    }
+
+    #[test]
+    fn to_regex_recursive() {
+        let rules: RuleSet = LOOPING_RULES.parse().unwrap();
+
+        assert!(rules.to_regex(0).is_err());
+        assert!(rules.to_regex(8).is_err());
+        assert_eq!("^(?:a)$", rules.to_regex(42).unwrap());
+    }
+
+    #[test]
+    fn compile() {
+        let rules: RuleSet = RULES.parse().unwrap();
+        let compiled = rules.compile(0, 6).unwrap();
+
+        for message in &["ababbb", "bababa", "abbbab", "aaabbb", "aaaabbb", ""] {
+            assert_eq!(rules.matches(0, message), compiled.matches(message), "{}", message);
+        }
+    }
+
+    #[test]
+    fn compile_recursive() {
+        let rules: RuleSet = LOOPING_RULES.parse().unwrap();
+        let compiled = rules.compile(0, 8).unwrap();
+
+        // 0 matches two or more a's followed by fewer b's (but at least one)
+        for message in &["aab", "aaab", "aaabb", "aaaabbb", "aaaaaaabbb", "ab", "aabb", "aaabbb", "ba", "aaba"] {
+            assert_eq!(rules.matches(0, message), compiled.matches(message), "{}", message);
+        }
+
+        assert!(compiled.matches("aaaaaaabbb"));
+        assert!(!compiled.matches("aaabbb"));
+    }
+
+    #[test]
+    fn compile_left_recursive() {
+        let rules: RuleSet = "0: 0 1 | 1\n1: \"a\"\n".parse().unwrap();
+        let compiled = rules.compile(0, 4).unwrap();
+
+        assert!(compiled.matches("a"));
+        assert!(compiled.matches("aa"));
+        assert!(compiled.matches("aaa"));
+        assert!(compiled.matches("aaaaaa"));
+        assert!(!compiled.matches("b"));
+        assert!(!compiled.matches("aab"));
+    }
+
+    #[test]
+    fn compile_doubly_recursive() {
+        // Unrolling this all the way would double the pattern with every two bytes
+        let rules: RuleSet = "0: 0 0 | 1 2\n1: \"a\"\n2: \"b\"\n".parse().unwrap();
+        let compiled = rules.compile(0, 90).unwrap();
+
+        assert!(compiled.matches("ab"));
+        assert!(compiled.matches(&"ab".repeat(45)));
+        assert!(!compiled.matches(&format!("{}ba", "ab".repeat(44))));
+        assert!(!compiled.matches(&format!("{}a", "ab".repeat(44))));
+    }
+
+    #[test]
+    fn compile_unmatchable() {
+        let rules: RuleSet = "0: 1 1 1\n1: \"a\"\n2: 2 1\n".parse().unwrap();
+
+        let compiled = rules.compile(0, 2).unwrap();
+        assert!(!compiled.matches("ab"));
+        assert!(!compiled.matches("b"));
+        assert!(compiled.matches("aaa"));
+
+        // Rule 2 never stops repeating, so it can't match anything at all
+        let compiled = rules.compile(2, 4).unwrap();
+        assert!(!compiled.matches("aa"));
+        assert!(!compiled.matches("aaaaaa"));
+    }
+
+    #[test]
+    fn matchers_agree() {
+        let grammars = [
+            RULES,
+            LOOPING_RULES,
+            "0: 0 1 | 1\n1: \"a\"\n",
+            "0: 2 3\n2: 0 4 | 4\n3: \"b\"\n4: \"a\"\n",
+            "0: 1 0 2 | 1 2 | 0 0\n1: \"a\"\n2: \"b\"\n",
+            "0: 3 | 1\n3: 0\n1: \"a\" | 1 2\n2: \"b\"\n",
+            "0: 0 0 | 1 2\n1: \"a\"\n2: \"b\"\n",
+        ];
+
+        let max_length = 8;
+
+        // Every message of a's and b's up to the budget, plus a couple of lengths past it
+        let mut messages = vec![String::new()];
+        let mut last = vec![String::new()];
+
+        for _ in 0..max_length + 2 {
+            last = last.iter()
+                .flat_map(|message| vec![format!("{}a", message), format!("{}b", message)])
+                .collect();
+
+            messages.extend(last.iter().cloned());
+        }
+
+        for grammar in &grammars {
+            let rules: RuleSet = grammar.parse().unwrap();
+            let compiled = rules.compile(0, max_length).unwrap();
+
+            for message in &messages {
+                assert_eq!(rules.matches(0, message), compiled.matches(message), "{:?} on {}", grammar, message);
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RuleSet {
+    pub(crate) rules: Vec<Vec<Rule>>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) enum Rule {
+    Literal(char),
+    SubRules(Vec<usize>),
+}
+
+impl FromStr for RuleSet {
+    type Err = ParseError;
+
+    fn from_str(rule_list: &str) -> Result<Self, Self::Err> {
+        let mut rule_set = RuleSet {
+            rules: Vec::new(),
+        };
+
+        for (i, line) in rule_list.lines().enumerate() {
+            rule_set.set_rule(line).map_err(|err| err.at_line(i + 1))?;
+        }
+
+        Ok(rule_set)
+    }
+}
+
+impl RuleSet {
+    /// Adds a rule, or replaces an existing rule with the same ID; the rule is written the same way
+    /// as a line of a rule list (like `8: 42 | 42 8`).
+    pub fn set_rule(&mut self, line: &str) -> Result<(), ParseError> {
+        let (id, rules) = Rule::parse_line(line)?;
+
+        // Rules don't have to be listed in order
+        if id >= self.rules.len() {
+            self.rules.resize_with(id + 1, Vec::new);
+        }
+
+        self.rules[id] = rules;
+
+        Ok(())
+    }
+
+    /// Checks whether the whole message matches the given rule. Rules may refer back to themselves
+    /// in any position, including first (like `0: 0 1 | 1`).
+    pub fn matches(&self, rule_id: usize, message: &str) -> bool {
+        let mut matcher = Matcher {
+            rules: self,
+            message,
+            ends: HashMap::new(),
+            finished: HashSet::new(),
+            in_progress: HashSet::new(),
+            used_estimate: false,
+            changed: false,
+        };
+
+        loop {
+            let ends = matcher.match_rule(rule_id, 0);
+
+            // Without left recursion, every answer was worked out in full the first time around
+            if !matcher.used_estimate || !matcher.changed {
+                return ends.contains(&message.len());
+            }
+
+            matcher.finished.clear();
+            matcher.used_estimate = false;
+            matcher.changed = false;
+        }
+    }
+}
+
+// Works out where matches for each rule could end, starting from each position in the message. A
+// rule that refers back to itself without matching anything first (left recursion) gets the best
+// answer known so far for the inner reference, and the whole search repeats until no answer grows;
+// since answers only ever grow, that settles on every possible match.
+struct Matcher<'a> {
+    rules: &'a RuleSet,
+    message: &'a str,
+
+    // The end positions found so far for each (rule, start) pair
+    ends: HashMap<(usize, usize), Vec<usize>>,
+
+    // Pairs already worked out during this pass through the search
+    finished: HashSet<(usize, usize)>,
+    in_progress: HashSet<(usize, usize)>,
+
+    used_estimate: bool,
+    changed: bool,
+}
+
+impl<'a> Matcher<'a> {
+    // Rules that loop (like `8: 42 | 42 8`) can match more or less of the message, and which one is
+    // right depends on what comes next, so every possible end position gets returned.
+    fn match_rule(&mut self, rule_id: usize, start: usize) -> Vec<usize> {
+        let key = (rule_id, start);
+
+        if self.finished.contains(&key) {
+            return self.ends.get(&key).cloned().unwrap_or_default();
+        }
+
+        if !self.in_progress.insert(key) {
+            self.used_estimate = true;
+            return self.ends.get(&key).cloned().unwrap_or_default();
+        }
+
+        let mut ends = Vec::new();
+
+        for alternative in self.rules.rules.get(rule_id).map_or(&[][..], Vec::as_slice) {
+            match alternative {
+                Rule::Literal(c) => {
+                    if self.message[start..].starts_with(*c) {
+                        ends.push(start + c.len_utf8());
+                    }
+                },
+
+                Rule::SubRules(sub_rules) => {
+                    let mut positions = vec![start];
+
+                    for sub_rule in sub_rules {
+                        let mut next_positions = Vec::new();
+
+                        for position in positions {
+                            next_positions.extend(self.match_rule(*sub_rule, position));
+                        }
+
+                        next_positions.sort_unstable();
+                        next_positions.dedup();
+                        positions = next_positions;
+
+                        if positions.is_empty() {
+                            break;
+                        }
+                    }
+
+                    ends.extend(positions);
+                },
+            }
+        }
+
+        ends.sort_unstable();
+        ends.dedup();
+
+        self.in_progress.remove(&key);
+        self.finished.insert(key);
+
+        if self.ends.get(&key).map_or(!ends.is_empty(), |previous| *previous != ends) {
+            self.changed = true;
+        }
+
+        self.ends.insert(key, ends.clone());
+
+        ends
+    }
+}
+
+impl Rule {
+    fn parse_line(line: &str) -> Result<(usize, Vec<Self>), ParseError> {
+        let (id, body) = line.split_once(':')
+            .ok_or_else(|| ParseError::new(line, line, "Expected a rule ID and a rule"))?;
+
+        let id: usize = id.parse()
+            .map_err(|_| ParseError::new(line, id, "Invalid rule ID"))?;
+
+        let rules = if let Some((_, literal)) = body.split_once('"') {
+            let c = literal.chars().next()
+                .filter(|c| *c != '"')
+                .ok_or_else(|| ParseError::new(line, literal, "Expected a character"))?;
+
+            vec![Rule::Literal(c)]
+        } else {
+            body.split('|')
+                .map(|block| {
+                    block.split(' ')
+                        .filter(|sub_rule| !sub_rule.is_empty())
+                        .map(|sub_rule| sub_rule.parse()
+                            .map_err(|_| ParseError::new(line, sub_rule, "Invalid sub-rule ID")))
+                        .collect::<Result<_, _>>()
+                        .map(Rule::SubRules)
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok((id, rules))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day19::message::{Rule, RuleSet};
+    use crate::parse::ParseError;
+
+    const LOOPING_RULES: &str = "42: 9 14 | 10 1\n9: 14 27 | 1 26\n10: 23 14 | 28 1\n1: \"a\"\n11: 42 31\n5: 1 14 | 15 1\n\
+        19: 14 1 | 14 14\n12: 24 14 | 19 1\n16: 15 1 | 14 14\n31: 14 17 | 1 13\n6: 14 14 | 1 14\n2: 1 24 | 14 4\n0: 8 11\n\
+        13: 14 3 | 1 12\n15: 1 | 14\n17: 14 2 | 1 7\n23: 25 1 | 22 14\n28: 16 1\n4: 1 1\n20: 14 14 | 1 15\n3: 5 14 | 16 1\n\
+        27: 1 6 | 14 18\n14: \"b\"\n21: 14 1 | 1 14\n25: 1 1 | 1 14\n22: 14 14\n8: 42\n26: 14 22 | 1 20\n18: 15 15\n\
+        7: 14 5 | 1 21\n24: 14 1\n";
+
+    const LOOPING_MESSAGES: [&str; 15] = [
+        "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa",
+        "bbabbbbaabaabba",
+        "babbbbaabbbbbabbbbbbaabaaabaaa",
+        "aaabbbbbbaaaabaababaabababbabaaabbababababaaa",
+        "bbbbbbbaaaabbbbaaabbabaaa",
+        "bbbababbbbaaaaaaaabbababaaababaabab",
+        "ababaaaaaabaaab",
+        "ababaaaaabbbaba",
+        "baabbaaaabbaaaababbaababb",
+        "abbbbabbbbaaaababbbbbbaaaababb",
+        "aaaaabbaabaaaaababaa",
+        "aaaabbaaaabbaaa",
+        "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa",
+        "babaaabbbaaabaababbaabababaaab",
+        "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
+    ];
+
+    #[test]
+    fn rule_from_string() {
+        assert_eq!((0, vec![Rule::SubRules(vec![1, 2])]), Rule::parse_line("0: 1 2").unwrap());
+        assert_eq!((1, vec![Rule::Literal('a')]), Rule::parse_line("1: \"a\"").unwrap());
+        assert_eq!((2, vec![Rule::SubRules(vec![1, 3]), Rule::SubRules(vec![3, 1])]), Rule::parse_line("2: 1 3 | 3 1").unwrap());
+        assert_eq!((3, vec![Rule::Literal('b')]), Rule::parse_line("3: \"b\"").unwrap());
+
+        assert_eq!(Err(ParseError { line: 1, column: 6, text: String::from("x"), message: String::from("Invalid sub-rule ID") }),
+                   Rule::parse_line("2: 1 x | 3 1"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("2 1 3"), message: String::from("Expected a rule ID and a rule") }),
+                   Rule::parse_line("2 1 3"));
+    }
+
+    #[test]
+    fn rule_set_from_string() {
+        let rules = "0: 1 2\n\
+                     1: \"a\"\n\
+                     2: 1 3 | 3 1\n\
+                     3: \"b\"\n";
+
+        let expected = RuleSet {
+            rules: vec![
+                vec![Rule::SubRules(vec![1, 2])],
+                vec![Rule::Literal('a')],
+                vec![Rule::SubRules(vec![1, 3]), Rule::SubRules(vec![3, 1])],
+                vec![Rule::Literal('b')],
+            ]
+        };
+
+        assert_eq!(Ok(expected), rules.parse());
+    }
+
+    #[test]
+    fn rule_set_out_of_order() {
+        let rules: RuleSet = "1: \"a\"\n0: 1 1\n".parse().unwrap();
+
+        assert_eq!(vec![vec![Rule::SubRules(vec![1, 1])], vec![Rule::Literal('a')]], rules.rules);
+    }
+
+    #[test]
+    fn rule_set_reports_line_numbers() {
+        let err = "0: 1 2\n1: \"a\"\n2: 1 3 |\n3 \"b\"\n".parse::<RuleSet>().unwrap_err();
+
+        assert_eq!(4, err.line);
+    }
+
+    #[test]
+    fn matches() {
+        let rules: RuleSet = "0: 4 1 5\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n".parse().unwrap();
+
+        assert!(rules.matches(0, "ababbb"));
+        assert!(rules.matches(0, "abbbab"));
+        assert!(!rules.matches(0, "bababa"));
+        assert!(!rules.matches(0, "aaabbb"));
+        assert!(!rules.matches(0, "aaaabbb"));
+        assert!(!rules.matches(0, ""));
+
+        assert!(rules.matches(4, "a"));
+        assert!(!rules.matches(6, "a"));
+    }
+
+    #[test]
+    fn matches_looping_rules() {
+        let mut rules: RuleSet = LOOPING_RULES.parse().unwrap();
+
+        assert_eq!(3, LOOPING_MESSAGES.iter().filter(|message| rules.matches(0, message)).count());
+
+        rules.set_rule("8: 42 | 42 8").unwrap();
+        rules.set_rule("11: 42 31 | 42 11 31").unwrap();
+
+        assert_eq!(12, LOOPING_MESSAGES.iter().filter(|message| rules.matches(0, message)).count());
+        assert!(rules.matches(0, "babbbbaabbbbbabbbbbbaabaaabaaa"));
+        assert!(!rules.matches(0, "aaaabbaaaabbaaa"));
+    }
+
+    #[test]
+    fn compile_looping_rules() {
+        let mut rules: RuleSet = LOOPING_RULES.parse().unwrap();
+        rules.set_rule("8: 42 | 42 8").unwrap();
+        rules.set_rule("11: 42 31 | 42 11 31").unwrap();
+
+        // Messages longer than the compiled length fall back to backtracking, so both should agree
+        for max_length in [20, 45] {
+            let compiled = rules.compile(0, max_length).unwrap();
+
+            assert_eq!(12, LOOPING_MESSAGES.iter().filter(|message| compiled.matches(message)).count());
+        }
+    }
+
+    #[test]
+    fn matches_left_recursive_rules() {
+        let rules: RuleSet = "0: 0 1 | 1\n1: \"a\"\n".parse().unwrap();
+
+        assert!(rules.matches(0, "a"));
+        assert!(rules.matches(0, "aa"));
+        assert!(rules.matches(0, "aaa"));
+        assert!(!rules.matches(0, "b"));
+        assert!(!rules.matches(0, "aab"));
+        assert!(!rules.matches(0, ""));
+
+        // Left recursion through another rule, with something on both sides
+        let rules: RuleSet = "0: 2 3\n2: 0 4 | 4\n3: \"b\"\n4: \"a\"\n".parse().unwrap();
+
+        assert!(rules.matches(0, "ab"));
+        assert!(rules.matches(0, "abab"));
+        assert!(rules.matches(0, "ababab"));
+        assert!(!rules.matches(0, "aabab"));
+        assert!(!rules.matches(0, "abb"));
+    }
+}
@@ -0,0 +1,45 @@
+use std::error;
+
+use itertools::Itertools;
+
+use crate::solution::{Answer, Solution};
+use crate::day19::message::RuleSet;
+
+pub mod compiled;
+pub mod message;
+
+pub struct Day19;
+
+impl Day19 {
+    fn count_matches(rules: &RuleSet, messages: &[String]) -> Result<usize, Box<dyn error::Error>> {
+        let max_length = messages.iter().map(String::len).max().unwrap_or(0);
+        let rule = rules.compile(0, max_length)?;
+
+        Ok(messages.iter()
+            .filter(|message| rule.matches(message))
+            .count())
+    }
+}
+
+impl Solution for Day19 {
+    type Input = (RuleSet, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let (rules, messages) = input.split_terminator("\n\n").collect_tuple()
+            .ok_or_else(|| simple_error::simple_error!("Expected a section of rules and a section of messages"))?;
+
+        Ok((rules.parse()?, messages.lines().map(String::from).collect()))
+    }
+
+    fn part1(&self, (rules, messages): &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(Day19::count_matches(rules, messages)?))
+    }
+
+    fn part2(&self, (rules, messages): &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let mut looping_rules = rules.clone();
+        looping_rules.set_rule("8: 42 | 42 8")?;
+        looping_rules.set_rule("11: 42 31 | 42 11 31")?;
+
+        Ok(Answer::from(Day19::count_matches(&looping_rules, messages)?))
+    }
+}
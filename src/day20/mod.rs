@@ -0,0 +1,53 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day20::tiles::Tile;
+use crate::parse;
+
+pub mod tiles;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let mut tiles = Vec::new();
+
+        for (lines_before, section) in parse::sections(input) {
+            if section.trim().is_empty() {
+                continue;
+            }
+
+            let tile: Tile = section.parse().map_err(|err: parse::ParseError| {
+                let line = err.line + lines_before;
+                err.at_line(line)
+            })?;
+
+            tiles.push(tile);
+        }
+
+        Ok(tiles)
+    }
+
+    fn part1(&self, tiles: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let corners = tiles::find_corners(tiles);
+
+        if corners.len() != 4 {
+            simple_error::bail!("Expected 4 corner tiles, but found {}", corners.len());
+        }
+
+        let product = corners.iter()
+            .try_fold(1u64, |product, id| product.checked_mul(*id))
+            .ok_or_else(|| simple_error::simple_error!("Product of corner tile IDs {:?} overflows", corners))?;
+
+        Ok(Answer::from(product))
+    }
+
+    fn part2(&self, tiles: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let image = tiles::assemble(tiles)
+            .ok_or_else(|| simple_error::simple_error!("Tiles can't be arranged into a square image"))?;
+
+        Ok(Answer::from(image.water_roughness()))
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+type Grid = Vec<Vec<bool>>;
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Tile {
+    pub id: u64,
+    pixels: Grid,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Image {
+    pixels: Grid,
+}
+
+fn rotate(grid: &[Vec<bool>]) -> Grid {
+    // Rotates clockwise; the new first row is the old first column, read from the bottom up
+    (0..grid[0].len())
+        .map(|column| grid.iter().rev().map(|row| row[column]).collect())
+        .collect()
+}
+
+fn flip(grid: &[Vec<bool>]) -> Grid {
+    grid.iter()
+        .map(|row| row.iter().rev().copied().collect())
+        .collect()
+}
+
+fn orientations(grid: &[Vec<bool>]) -> Vec<Grid> {
+    let mut orientations = Vec::with_capacity(8);
+    let mut current = grid.to_vec();
+
+    for _ in 0..4 {
+        orientations.push(flip(&current));
+        current = rotate(&current);
+        orientations.push(current.clone());
+    }
+
+    orientations
+}
+
+impl Tile {
+    fn top(&self) -> Vec<bool> {
+        self.pixels[0].clone()
+    }
+
+    fn bottom(&self) -> Vec<bool> {
+        self.pixels[self.pixels.len() - 1].clone()
+    }
+
+    fn left(&self) -> Vec<bool> {
+        self.pixels.iter().map(|row| row[0]).collect()
+    }
+
+    fn right(&self) -> Vec<bool> {
+        self.pixels.iter().map(|row| row[row.len() - 1]).collect()
+    }
+
+    fn edges(&self) -> [Vec<bool>; 4] {
+        [self.top(), self.right(), self.bottom(), self.left()]
+    }
+
+    fn orientations(&self) -> impl Iterator<Item = Tile> + '_ {
+        orientations(&self.pixels).into_iter()
+            .map(move |pixels| Tile { id: self.id, pixels })
+    }
+
+    fn without_border(&self) -> Grid {
+        self.pixels[1..(self.pixels.len() - 1)].iter()
+            .map(|row| row[1..(row.len() - 1)].to_vec())
+            .collect()
+    }
+}
+
+/// Every edge of every tile, in both directions, along with the ID of the tile it came from.
+fn edges_by_tile(tiles: &[Tile]) -> Vec<(u64, Vec<bool>)> {
+    tiles.iter()
+        .flat_map(|tile| {
+            tile.edges().iter()
+                .flat_map(|edge| vec![(tile.id, edge.clone()), (tile.id, edge.iter().rev().copied().collect())])
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn is_shared(edge: &[bool], tile_id: u64, edges: &[(u64, Vec<bool>)]) -> bool {
+    edges.iter().any(|(id, candidate)| *id != tile_id && candidate.as_slice() == edge)
+}
+
+/// Corner tiles are the only ones with two edges that don't line up with any other tile.
+pub fn find_corners(tiles: &[Tile]) -> Vec<u64> {
+    let edges = edges_by_tile(tiles);
+
+    tiles.iter()
+        .filter(|tile| tile.edges().iter().filter(|edge| is_shared(edge, tile.id, &edges)).count() == 2)
+        .map(|tile| tile.id)
+        .collect()
+}
+
+/// Arranges the tiles into a square and joins them into a single image, trimming each tile's border.
+/// Returns `None` if the tiles can't be arranged.
+pub fn assemble(tiles: &[Tile]) -> Option<Image> {
+    let size = (1..=tiles.len()).find(|size| size * size >= tiles.len())?;
+
+    if size * size != tiles.len() {
+        return None;
+    }
+
+    let edges = edges_by_tile(tiles);
+
+    // Start from any corner, turned so that its unmatched edges are on the top and left
+    let corner_id = *find_corners(tiles).first()?;
+    let corner = tiles.iter().find(|tile| tile.id == corner_id)?
+        .orientations()
+        .find(|tile| !is_shared(&tile.top(), tile.id, &edges) && !is_shared(&tile.left(), tile.id, &edges))?;
+
+    let mut used = HashSet::new();
+    used.insert(corner.id);
+
+    let mut arranged: Vec<Tile> = vec![corner];
+
+    for position in 1..tiles.len() {
+        let left = if position % size > 0 { Some(arranged[position - 1].right()) } else { None };
+        let above = if position >= size { Some(arranged[position - size].bottom()) } else { None };
+
+        let next = tiles.iter()
+            .filter(|tile| !used.contains(&tile.id))
+            .flat_map(|tile| tile.orientations())
+            .find(|tile| left.as_ref().is_none_or(|edge| *edge == tile.left()) &&
+                above.as_ref().is_none_or(|edge| *edge == tile.top()))?;
+
+        used.insert(next.id);
+        arranged.push(next);
+    }
+
+    let mut pixels = Vec::new();
+
+    for row in arranged.chunks(size) {
+        let trimmed: Vec<Grid> = row.iter().map(Tile::without_border).collect();
+
+        for line in 0..trimmed[0].len() {
+            pixels.push(trimmed.iter().flat_map(|grid| grid[line].iter().copied()).collect());
+        }
+    }
+
+    Some(Image { pixels })
+}
+
+impl Image {
+    fn find_sea_monsters(pixels: &[Vec<bool>]) -> HashSet<(usize, usize)> {
+        let monster: Vec<(usize, usize)> = SEA_MONSTER.iter().enumerate()
+            .flat_map(|(row, line)| {
+                line.chars().enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(column, _)| (row, column))
+            })
+            .collect();
+
+        let monster_height = SEA_MONSTER.len();
+        let monster_width = SEA_MONSTER[0].len();
+
+        let mut monster_pixels = HashSet::new();
+
+        for row in 0..(pixels.len().saturating_sub(monster_height - 1)) {
+            for column in 0..(pixels[row].len().saturating_sub(monster_width - 1)) {
+                if monster.iter().all(|(r, c)| pixels[row + r][column + c]) {
+                    monster_pixels.extend(monster.iter().map(|(r, c)| (row + r, column + c)));
+                }
+            }
+        }
+
+        monster_pixels
+    }
+
+    /// Counts the rough water: the set pixels that aren't part of a sea monster in whichever
+    /// orientation of the image has sea monsters.
+    pub fn water_roughness(&self) -> usize {
+        let set_pixels = self.pixels.iter().flatten().filter(|pixel| **pixel).count();
+
+        let monster_pixels = orientations(&self.pixels).iter()
+            .map(|pixels| Image::find_sea_monsters(pixels).len())
+            .max()
+            .unwrap_or(0);
+
+        set_pixels - monster_pixels
+    }
+}
+
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    fn from_str(section: &str) -> Result<Self, Self::Err> {
+        let mut lines = section.lines();
+        let header = lines.next().unwrap_or("");
+
+        let id = header.strip_prefix("Tile ")
+            .and_then(|id| id.strip_suffix(':'))
+            .ok_or_else(|| ParseError::new(header, header, "Expected a tile header like \"Tile 1234:\""))?;
+
+        let id = id.parse()
+            .map_err(|_| ParseError::new(header, id, "Invalid tile ID"))?;
+
+        let rows: Vec<&str> = lines.collect();
+        let mut pixels = Vec::with_capacity(rows.len());
+
+        for (i, line) in rows.iter().enumerate() {
+            let row = line.char_indices()
+                .map(|(j, c)| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(ParseError::new(line, &line[j..(j + c.len_utf8())], "Pixels must be '#' or '.'").at_line(i + 2)),
+                })
+                .collect::<Result<Vec<bool>, ParseError>>()?;
+
+            if row.len() != rows.len() {
+                return Err(ParseError::new(line, line, "Tiles must be square").at_line(i + 2));
+            }
+
+            pixels.push(row);
+        }
+
+        // Assembling the image trims each tile's border, so there has to be one
+        if pixels.len() < 2 {
+            return Err(ParseError::new(header, header, "Tiles must be at least 2 pixels across"));
+        }
+
+        Ok(Tile { id, pixels })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day20::Day20;
+    use crate::day20::tiles::{self, Tile};
+    use crate::parse::ParseError;
+    use crate::solution::{Answer, Solution};
+
+    const EXAMPLE: &str = "Tile 2311:\n..##.#..#.\n##..#.....\n#...##..#.\n####.#...#\n##.##.###.\n##...#.###\n.#.#.#..##\n..#....#..\n###...#.#.\n..###..###\n\n\
+        Tile 1951:\n#.##...##.\n#.####...#\n.....#..##\n#...######\n.##.#....#\n.###.#####\n###.##.##.\n.###....#.\n..#.#..#.#\n#...##.#..\n\n\
+        Tile 1171:\n####...##.\n#..##.#..#\n##.#..#.#.\n.###.####.\n..###.####\n.##....##.\n.#...####.\n#.##.####.\n####..#...\n.....##...\n\n\
+        Tile 1427:\n###.##.#..\n.#..#.##..\n.#.##.#..#\n#.#.#.##.#\n....#...##\n...##..##.\n...#.#####\n.#.####.#.\n..#..###.#\n..##.#..#.\n\n\
+        Tile 1489:\n##.#.#....\n..##...#..\n.##..##...\n..#...#...\n#####...#.\n#..#.#.#.#\n...#.#.#..\n##.#...##.\n..##.##.##\n###.##.#..\n\n\
+        Tile 2473:\n#....####.\n#..#.##...\n#.##..#...\n######.#.#\n.#...#.#.#\n.#########\n.###.#..#.\n########.#\n##...##.#.\n..###.#.#.\n\n\
+        Tile 2971:\n..#.#....#\n#...###...\n#.#.###...\n##.##..#..\n.#####..##\n.#..####.#\n#..#.#..#.\n..####.###\n..#.#.###.\n...#.#.#.#\n\n\
+        Tile 2729:\n...#.#.#.#\n####.#....\n..#.#.....\n....#..#.#\n.##..##.#.\n.#.####...\n####.#.#..\n##.####...\n##..#.##..\n#.##...##.\n\n\
+        Tile 3079:\n#.#.#####.\n.#..######\n..#.......\n######....\n####.#..#.\n.#...#.##.\n#.#####.##\n..#.###...\n..#.......\n..#.###...\n";
+
+    fn example_tiles() -> Vec<Tile> {
+        EXAMPLE.split("\n\n").map(|section| section.parse().unwrap()).collect()
+    }
+
+    fn grid(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect()
+    }
+
+    #[test]
+    fn tile_from_str() {
+        let tile: Tile = "Tile 42:\n#.\n.#".parse().unwrap();
+
+        assert_eq!(42, tile.id);
+        assert_eq!(grid(&["#.", ".#"]), tile.pixels);
+
+        assert_eq!(Err(ParseError { line: 1, column: 6, text: String::from("x"), message: String::from("Invalid tile ID") }),
+                   "Tile x:\n#.\n.#".parse::<Tile>());
+
+        assert_eq!(Err(ParseError { line: 3, column: 2, text: String::from("o"), message: String::from("Pixels must be '#' or '.'") }),
+                   "Tile 42:\n#.\n.o".parse::<Tile>());
+
+        assert!("Tile 42:\n#.\n.#.".parse::<Tile>().is_err());
+        assert!("Tile 42:\n#.".parse::<Tile>().is_err());
+    }
+
+    #[test]
+    fn rotate_and_flip() {
+        let original = grid(&["#..", "...", "..."]);
+
+        assert_eq!(grid(&["..#", "...", "..."]), tiles::rotate(&original));
+        assert_eq!(grid(&["..#", "...", "..."]), tiles::flip(&original));
+
+        let orientations = tiles::orientations(&grid(&["##.", "...", "..."]));
+        assert_eq!(8, orientations.len());
+        assert!(orientations.contains(&grid(&["##.", "...", "..."])));
+    }
+
+    #[test]
+    fn find_corners() {
+        let mut corners = tiles::find_corners(&example_tiles());
+        corners.sort_unstable();
+
+        assert_eq!(vec![1171, 1951, 2971, 3079], corners);
+    }
+
+    #[test]
+    fn corner_product() {
+        assert_eq!(Answer::from(20899048083289u64), Day20.part1(&example_tiles()).unwrap());
+
+        let tiles = Day20.parse(&EXAMPLE.replace("Tile 1951:", "Tile 99999999999:").replace("Tile 3079:", "Tile 99999999999:"))
+            .unwrap();
+
+        assert!(Day20.part1(&tiles).is_err());
+    }
+
+    #[test]
+    fn assemble() {
+        let image = tiles::assemble(&example_tiles()).unwrap();
+
+        assert_eq!(24, image.pixels.len());
+        assert!(image.pixels.iter().all(|row| row.len() == 24));
+        assert_eq!(273, image.water_roughness());
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::{BTreeMap, HashSet};
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+/// For each allergen, the ingredients that appear in every food known to contain it.
+fn get_candidate_ingredients(foods: &[Food]) -> BTreeMap<&str, HashSet<&str>> {
+    let mut candidates: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+
+    for food in foods {
+        let ingredients: HashSet<&str> = food.ingredients.iter().map(String::as_str).collect();
+
+        for allergen in &food.allergens {
+            candidates.entry(allergen.as_str())
+                .and_modify(|candidates| candidates.retain(|ingredient| ingredients.contains(ingredient)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+
+    candidates
+}
+
+pub fn count_safe_ingredients(foods: &[Food]) -> usize {
+    let unsafe_ingredients: HashSet<&str> = get_candidate_ingredients(foods).into_values()
+        .flatten()
+        .collect();
+
+    foods.iter()
+        .flat_map(|food| food.ingredients.iter())
+        .filter(|ingredient| !unsafe_ingredients.contains(ingredient.as_str()))
+        .count()
+}
+
+/// Pairs each allergen with the one ingredient that contains it, sorted by allergen. Returns `None`
+/// if the foods don't narrow every allergen down to a single ingredient.
+pub fn find_allergen_sources(foods: &[Food]) -> Option<Vec<(String, String)>> {
+    let mut candidates = get_candidate_ingredients(foods);
+    let mut sources = BTreeMap::new();
+
+    while !candidates.is_empty() {
+        let (allergen, ingredient) = candidates.iter()
+            .find(|(_, ingredients)| ingredients.len() == 1)
+            .map(|(allergen, ingredients)| (*allergen, *ingredients.iter().next().unwrap()))?;
+
+        candidates.remove(allergen);
+        candidates.values_mut().for_each(|ingredients| {
+            ingredients.remove(ingredient);
+        });
+
+        sources.insert(String::from(allergen), String::from(ingredient));
+    }
+
+    Some(sources.into_iter().collect())
+}
+
+impl FromStr for Food {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (ingredients, allergens) = match line.split_once(" (contains ") {
+            Some((ingredients, allergens)) => {
+                let allergens = allergens.strip_suffix(')')
+                    .ok_or_else(|| ParseError::new(line, allergens, "Expected a closing ')' after allergens"))?;
+
+                (ingredients, allergens.split(", ").map(String::from).collect())
+            },
+            None => (line, Vec::new()),
+        };
+
+        if let Some(unexpected) = ingredients.split(' ').find(|ingredient| ingredient.is_empty() || !ingredient.chars().all(char::is_alphabetic)) {
+            return Err(ParseError::new(line, unexpected, "Ingredients must be words separated by spaces"));
+        }
+
+        Ok(Food {
+            ingredients: ingredients.split(' ').map(String::from).collect(),
+            allergens,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day21::food::{self, Food};
+    use crate::parse::{self, ParseError};
+
+    const EXAMPLE: &str = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)\n\
+                           trh fvjkl sbzzf mxmxvkd (contains dairy)\n\
+                           sqjhc fvjkl (contains soy)\n\
+                           sqjhc mxmxvkd sbzzf (contains fish)\n";
+
+    fn strings(strings: &[&str]) -> Vec<String> {
+        strings.iter().map(|string| string.to_string()).collect()
+    }
+
+    #[test]
+    fn food_from_str() {
+        assert_eq!(Ok(Food { ingredients: strings(&["trh", "fvjkl"]), allergens: strings(&["dairy", "fish"]) }),
+                   "trh fvjkl (contains dairy, fish)".parse());
+
+        assert_eq!(Ok(Food { ingredients: strings(&["trh"]), allergens: vec![] }), "trh".parse());
+
+        assert_eq!(Err(ParseError { line: 1, column: 15, text: String::from("dairy"), message: String::from("Expected a closing ')' after allergens") }),
+                   "trh (contains dairy".parse::<Food>());
+
+        assert_eq!(Err(ParseError { line: 1, column: 5, text: String::from(""), message: String::from("Ingredients must be words separated by spaces") }),
+                   "trh  fvjkl".parse::<Food>());
+    }
+
+    #[test]
+    fn count_safe_ingredients() {
+        let foods: Vec<Food> = parse::parse_lines(EXAMPLE).unwrap();
+
+        assert_eq!(5, food::count_safe_ingredients(&foods));
+    }
+
+    #[test]
+    fn find_allergen_sources() {
+        let foods: Vec<Food> = parse::parse_lines(EXAMPLE).unwrap();
+
+        let expected = vec![
+            (String::from("dairy"), String::from("mxmxvkd")),
+            (String::from("fish"), String::from("sqjhc")),
+            (String::from("soy"), String::from("fvjkl")),
+        ];
+
+        assert_eq!(Some(expected), food::find_allergen_sources(&foods));
+    }
+
+    #[test]
+    fn find_allergen_sources_ambiguous() {
+        let foods: Vec<Food> = parse::parse_lines("a b (contains dairy)\n").unwrap();
+
+        assert_eq!(None, food::find_allergen_sources(&foods));
+    }
+}
@@ -0,0 +1,31 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day21::food::Food;
+use crate::parse;
+
+pub mod food;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Food>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part1(&self, foods: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(food::count_safe_ingredients(foods)))
+    }
+
+    fn part2(&self, foods: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let dangerous = food::find_allergen_sources(foods)
+            .ok_or_else(|| simple_error::simple_error!("Couldn't work out which ingredient contains each allergen"))?;
+
+        Ok(Answer::from(dangerous.into_iter()
+            .map(|(_, ingredient)| ingredient)
+            .collect::<Vec<String>>()
+            .join(",")))
+    }
+}
@@ -29,7 +29,7 @@ fn get_combat_score(deck: &VecDeque<u32>) -> u32 {
 
 #[cfg(test)]
 mod test {
-    use crate::cards;
+    use crate::day22::cards;
     use std::collections::VecDeque;
 
     #[test]
@@ -0,0 +1,39 @@
+use std::collections::VecDeque;
+use std::error;
+
+use itertools::Itertools;
+
+use crate::solution::{Answer, Solution};
+
+pub mod cards;
+
+pub struct Day22;
+
+impl Day22 {
+    fn parse_deck(section: &str) -> Result<VecDeque<u32>, Box<dyn error::Error>> {
+        // The first line just names the player
+        Ok(section.lines()
+            .skip(1)
+            .map(|card| card.parse())
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+impl Solution for Day22 {
+    type Input = (VecDeque<u32>, VecDeque<u32>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let (first, second) = input.split_terminator("\n\n").collect_tuple()
+            .ok_or_else(|| simple_error::simple_error!("Expected exactly two decks"))?;
+
+        Ok((Day22::parse_deck(first)?, Day22::parse_deck(second)?))
+    }
+
+    fn part1(&self, decks: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(cards::play_combat(decks.clone())))
+    }
+
+    fn part2(&self, _decks: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::Unsolved)
+    }
+}
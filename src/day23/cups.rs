@@ -0,0 +1,155 @@
+use crate::parse::ParseError;
+
+/// A ring of cups, stored as the label of the cup clockwise from each cup so that moving three cups
+/// around the ring doesn't mean shifting everything in between.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Cups {
+    // next[label] is the label of the cup after the cup with the given label; next[0] is unused
+    next: Vec<u32>,
+    current: u32,
+}
+
+/// Parses a line of cup labels, which must be the digits 1 to n in some order.
+pub fn parse_labels(line: &str) -> Result<Vec<u32>, ParseError> {
+    let labels = line.char_indices()
+        .map(|(i, c)| c.to_digit(10)
+            .filter(|label| *label > 0)
+            .ok_or_else(|| ParseError::new(line, &line[i..(i + c.len_utf8())], "Cup labels must be digits from 1 to 9")))
+        .collect::<Result<Vec<u32>, ParseError>>()?;
+
+    for (i, label) in labels.iter().enumerate() {
+        if *label as usize > labels.len() || labels[..i].contains(label) {
+            return Err(ParseError::new(line, &line[i..(i + 1)], "Cups must be labeled 1 to n with no repeats"));
+        }
+    }
+
+    // Each move picks up three cups and needs somewhere other than the current cup to put them
+    if labels.len() < 5 {
+        return Err(ParseError::new(line, line, "Expected at least 5 cups"));
+    }
+
+    Ok(labels)
+}
+
+impl Cups {
+    /// Places the given cups in the ring, followed by cups labeled in order from one more than the
+    /// highest given label until there are `total` cups.
+    pub fn new(labels: &[u32], total: usize) -> Self {
+        let total = total.max(labels.len());
+
+        let all_labels: Vec<u32> = labels.iter().copied()
+            .chain((labels.len() as u32 + 1)..=(total as u32))
+            .collect();
+
+        let mut next = vec![0; total + 1];
+
+        for (i, label) in all_labels.iter().enumerate() {
+            next[*label as usize] = all_labels[(i + 1) % all_labels.len()];
+        }
+
+        Cups {
+            next,
+            current: all_labels[0],
+        }
+    }
+
+    fn len(&self) -> u32 {
+        self.next.len() as u32 - 1
+    }
+
+    pub fn play(&mut self, moves: usize) {
+        for _ in 0..moves {
+            let first = self.next[self.current as usize];
+            let second = self.next[first as usize];
+            let third = self.next[second as usize];
+
+            // Pull the three cups after the current cup out of the ring
+            self.next[self.current as usize] = self.next[third as usize];
+
+            let mut destination = self.current;
+
+            loop {
+                destination = if destination == 1 { self.len() } else { destination - 1 };
+
+                if destination != first && destination != second && destination != third {
+                    break;
+                }
+            }
+
+            // ...and put them back in after the destination cup
+            self.next[third as usize] = self.next[destination as usize];
+            self.next[destination as usize] = first;
+
+            self.current = self.next[self.current as usize];
+        }
+    }
+
+    /// The labels of every cup after cup 1, going clockwise.
+    pub fn labels_after_one(&self) -> String {
+        let mut labels = String::new();
+        let mut label = self.next[1];
+
+        while label != 1 {
+            labels.push_str(&label.to_string());
+            label = self.next[label as usize];
+        }
+
+        labels
+    }
+
+    /// The product of the labels of the two cups after cup 1.
+    pub fn product_after_one(&self) -> u64 {
+        let first = self.next[1];
+        let second = self.next[first as usize];
+
+        first as u64 * second as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::day23::cups::{self, Cups};
+    use crate::parse::ParseError;
+
+    #[test]
+    fn parse_labels() {
+        assert_eq!(Ok(vec![3, 8, 9, 1, 2, 5, 4, 6, 7]), cups::parse_labels("389125467"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 3, text: String::from("x"), message: String::from("Cup labels must be digits from 1 to 9") }),
+                   cups::parse_labels("38x"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 3, text: String::from("3"), message: String::from("Cups must be labeled 1 to n with no repeats") }),
+                   cups::parse_labels("323"));
+
+        assert!(cups::parse_labels("124").is_err());
+        assert!(cups::parse_labels("").is_err());
+        assert!(cups::parse_labels("2143").is_err());
+    }
+
+    #[test]
+    fn new() {
+        let cups = Cups::new(&[3, 1, 2], 5);
+
+        assert_eq!(vec![0, 2, 4, 1, 5, 3], cups.next);
+        assert_eq!(3, cups.current);
+    }
+
+    #[test]
+    fn play() {
+        let mut cups = Cups::new(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 9);
+
+        cups.play(10);
+        assert_eq!("92658374", cups.labels_after_one());
+
+        cups.play(90);
+        assert_eq!("67384529", cups.labels_after_one());
+    }
+
+    #[test]
+    fn play_million_cups() {
+        let mut cups = Cups::new(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 1_000_000);
+        cups.play(10_000_000);
+
+        assert_eq!(149245887792, cups.product_after_one());
+    }
+}
@@ -0,0 +1,30 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+use crate::day23::cups::Cups;
+
+pub mod cups;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(cups::parse_labels(input.trim())?)
+    }
+
+    fn part1(&self, labels: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let mut cups = Cups::new(labels, labels.len());
+        cups.play(100);
+
+        Ok(Answer::from(cups.labels_after_one()))
+    }
+
+    fn part2(&self, labels: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let mut cups = Cups::new(labels, 1_000_000);
+        cups.play(10_000_000);
+
+        Ok(Answer::from(cups.product_after_one()))
+    }
+}
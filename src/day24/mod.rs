@@ -0,0 +1,23 @@
+use std::error;
+
+use crate::solution::{Answer, Solution};
+
+pub mod tile;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::from(tile::count_flipped_tiles(lines.clone())?))
+    }
+
+    fn part2(&self, _lines: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::Unsolved)
+    }
+}
@@ -1,26 +1,29 @@
 use std::ops::{Add, AddAssign};
-use crate::tile::HexDirection::*;
+use crate::day24::tile::HexDirection::*;
 use std::iter::Sum;
 use std::collections::HashSet;
+use std::str::FromStr;
 
-pub fn count_flipped_tiles(direction_lines: Vec<String>) -> usize {
+use crate::parse::ParseError;
+
+pub fn count_flipped_tiles(direction_lines: Vec<String>) -> Result<usize, ParseError> {
     let mut flipped_tiles = HashSet::new();
 
-    direction_lines.iter()
-        .map(|line| HexDirection::parse_line(line.as_str()))
-        .map(|directions| HexVector::from(directions))
-        .for_each(|hex_vector| {
-            if flipped_tiles.contains(&hex_vector) {
-                flipped_tiles.remove(&hex_vector);
-            } else {
-                flipped_tiles.insert(hex_vector);
-            }
-        });
-
-    flipped_tiles.len()
+    for (i, line) in direction_lines.iter().enumerate() {
+        let hex_vector = HexVector::from(HexDirection::parse_line(line.as_str())
+            .map_err(|err| err.at_line(i + 1))?);
+
+        if flipped_tiles.contains(&hex_vector) {
+            flipped_tiles.remove(&hex_vector);
+        } else {
+            flipped_tiles.insert(hex_vector);
+        }
+    }
+
+    Ok(flipped_tiles.len())
 }
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Eq, PartialEq, Hash)]
 struct HexVector {
     q: i32,
     r: i32,
@@ -36,14 +39,6 @@ enum HexDirection {
     NorthEast,
 }
 
-impl Default for HexVector {
-    fn default() -> Self {
-        HexVector {
-            q: 0,
-            r: 0,
-        }
-    }
-}
 
 impl From<&HexDirection> for HexVector {
     fn from(direction: &HexDirection) -> Self {
@@ -66,7 +61,7 @@ impl From<&HexDirection> for HexVector {
 impl From<Vec<HexDirection>> for HexVector {
     fn from(directions: Vec<HexDirection>) -> Self {
         directions.iter()
-            .map(|direction| HexVector::from(direction))
+            .map(HexVector::from)
             .sum()
     }
 }
@@ -101,40 +96,56 @@ impl Sum for HexVector {
     }
 }
 
+impl FromStr for HexDirection {
+    type Err = ParseError;
+
+    fn from_str(direction: &str) -> Result<Self, Self::Err> {
+        match direction {
+            "e" => Ok(East),
+            "se" => Ok(SouthEast),
+            "sw" => Ok(SouthWest),
+            "w" => Ok(West),
+            "nw" => Ok(NorthWest),
+            "ne" => Ok(NorthEast),
+            _ => Err(ParseError::new(direction, direction, "Unknown direction")),
+        }
+    }
+}
+
 impl HexDirection {
 
-    fn parse_line(line: &str) -> Vec<HexDirection> {
+    fn parse_line(line: &str) -> Result<Vec<HexDirection>, ParseError> {
         let mut offset = 0;
         let mut directions = Vec::new();
 
         while offset < line.len() {
-            let (direction, delta_offset) = match &line[offset..(offset + 1)] {
-                "e" => (East, 1),
-                "w" => (West, 1),
-                _ => {
-                    match &line[offset..(offset + 2)] {
-                        "se" => (SouthEast, 2),
-                        "sw" => (SouthWest, 2),
-                        "nw" => (NorthWest, 2),
-                        "ne" => (NorthEast, 2),
-                        _ => unreachable!()
-                    }
-                }
+            // North and south are always followed by east or west
+            let length = match line.as_bytes()[offset] {
+                b'n' | b's' => 2,
+                _ => 1,
             };
 
+            let token = line.get(offset..(offset + length))
+                .or_else(|| line.get(offset..))
+                .unwrap_or("");
+
+            let direction = token.parse()
+                .map_err(|_| ParseError::new(line, token, "Unknown direction"))?;
+
             directions.push(direction);
-            offset += delta_offset
+            offset += token.len().max(1);
         }
 
-        directions
+        Ok(directions)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::tile::{HexVector, HexDirection};
-    use crate::tile::HexDirection::{East, SouthEast, NorthEast, NorthWest, West, SouthWest};
-    use crate::tile;
+    use crate::day24::tile::{HexVector, HexDirection};
+    use crate::day24::tile::HexDirection::{East, SouthEast, NorthEast, NorthWest, West, SouthWest};
+    use crate::day24::tile;
+    use crate::parse::ParseError;
 
     #[test]
     fn add_hex_vector() {
@@ -143,14 +154,23 @@ mod test {
 
     #[test]
     fn parse_line() {
-        assert_eq!(vec![East, SouthEast, NorthEast, East], HexDirection::parse_line("esenee"));
-        assert_eq!(vec![NorthWest, West, SouthWest, East, East], HexDirection::parse_line("nwwswee"));
+        assert_eq!(vec![East, SouthEast, NorthEast, East], HexDirection::parse_line("esenee").unwrap());
+        assert_eq!(vec![NorthWest, West, SouthWest, East, East], HexDirection::parse_line("nwwswee").unwrap());
+
+        assert_eq!(Err(ParseError { line: 1, column: 3, text: String::from("x"), message: String::from("Unknown direction") }),
+                   HexDirection::parse_line("nex"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 2, text: String::from("n"), message: String::from("Unknown direction") }),
+                   HexDirection::parse_line("en"));
+
+        assert_eq!(Err(ParseError { line: 1, column: 1, text: String::from("nn"), message: String::from("Unknown direction") }),
+                   HexDirection::parse_line("nne"));
     }
 
     #[test]
     fn from_direction_list() {
-        assert_eq!(HexVector { q: 0, r: 1 }, HexVector::from(HexDirection::parse_line("esew")));
-        assert_eq!(HexVector::default(), HexVector::from(HexDirection::parse_line("nwwswee")));
+        assert_eq!(HexVector { q: 0, r: 1 }, HexVector::from(HexDirection::parse_line("esew").unwrap()));
+        assert_eq!(HexVector::default(), HexVector::from(HexDirection::parse_line("nwwswee").unwrap()));
     }
 
     #[test]
@@ -178,6 +198,7 @@ mod test {
             String::from("wseweeenwnesenwwwswnew"),
         ];
 
-        assert_eq!(10, tile::count_flipped_tiles(direction_lines));
+        assert_eq!(Ok(10), tile::count_flipped_tiles(direction_lines));
+        assert_eq!(3, tile::count_flipped_tiles(vec![String::from("e"), String::from("nw"), String::from("x")]).unwrap_err().line);
     }
 }
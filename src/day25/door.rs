@@ -24,7 +24,7 @@ pub fn get_encryption_key(public_key: u64, loop_size: u64) -> u64 {
 
 #[cfg(test)]
 mod test {
-    use crate::door;
+    use crate::day25::door;
 
     #[test]
     pub fn get_loop_size() {
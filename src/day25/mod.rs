@@ -0,0 +1,32 @@
+use std::error;
+
+use itertools::Itertools;
+
+use crate::solution::{Answer, Solution};
+
+pub mod door;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    // The card's public key, then the door's
+    type Input = (u64, u64);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>> {
+        let (card_public_key, door_public_key) = input.lines().collect_tuple()
+            .ok_or_else(|| simple_error::simple_error!("Expected exactly two public keys"))?;
+
+        Ok((card_public_key.parse()?, door_public_key.parse()?))
+    }
+
+    fn part1(&self, (card_public_key, door_public_key): &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        let door_loop_size = door::get_loop_size(7, *door_public_key);
+
+        Ok(Answer::from(door::get_encryption_key(*card_public_key, door_loop_size)))
+    }
+
+    // Day 25 only has one puzzle
+    fn part2(&self, _public_keys: &Self::Input) -> Result<Answer, Box<dyn error::Error>> {
+        Ok(Answer::Unsolved)
+    }
+}
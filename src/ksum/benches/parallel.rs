@@ -0,0 +1,30 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use ksum::{KSumIter, SortedSlice};
+
+// A fixed linear congruential generator keeps the input identical from run to run
+fn numbers(n: usize) -> Vec<i64> {
+    let mut state: u64 = 0x2020_1201;
+
+    (0..n)
+        .map(|_| {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) % 2_000_001) as i64 - 1_000_000
+        })
+        .collect()
+}
+
+fn three_sum(c: &mut Criterion) {
+    let mut numbers = numbers(10_000);
+    let numbers = SortedSlice::sort(&mut numbers);
+
+    let mut group = c.benchmark_group("3-sum of 10,000 numbers");
+    group.sample_size(10);
+
+    group.bench_function("sequential", |b| b.iter(|| KSumIter::new(numbers, 3, 0).unwrap().count()));
+    group.bench_function("parallel", |b| b.iter(|| ksum::par_ksum(numbers, 3, 0).unwrap().len()));
+
+    group.finish();
+}
+
+criterion_group!(benches, three_sum);
+criterion_main!(benches);
@@ -0,0 +1,189 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::{check_k, Integer, KSumError, SortedSlice, WideSum};
+
+// The distance from the target, the sum itself, and the numbers making it up
+type Best<T> = Option<(WideSum<T>, WideSum<T>, Vec<T>)>;
+
+/// Finds the k numbers whose sum is closest to the target. If several sums are equally close, the
+/// smaller sum wins.
+pub fn closest_ksum<T: Integer>(numbers: SortedSlice<T>, k: usize, target: T) -> Result<Vec<T>, KSumError> {
+    check_k(numbers.len(), k)?;
+
+    let mut best = None;
+    closest_with_prefix(numbers.as_slice(), 0, k, WideSum::new(target), &mut Vec::with_capacity(k), WideSum::new(T::zero()), &mut best);
+
+    // check_k guarantees there's at least one candidate
+    Ok(best.map(|(_, _, solution)| solution).unwrap())
+}
+
+/// Finds every distinct combination of k numbers whose sum falls within the given range.
+pub fn ksum_in_range<T: Integer>(numbers: SortedSlice<T>, k: usize, range: RangeInclusive<T>) -> Result<HashSet<Vec<T>>, KSumError> {
+    check_k(numbers.len(), k)?;
+
+    let range = WideSum::new(*range.start())..=WideSum::new(*range.end());
+
+    let mut solutions = HashSet::new();
+    in_range_with_prefix(numbers.as_slice(), 0, k, &range, &mut Vec::with_capacity(k), WideSum::new(T::zero()), &mut solutions);
+
+    Ok(solutions)
+}
+
+fn closest_with_prefix<T: Integer>(numbers: &[T], start: usize, k: usize, target: WideSum<T>, prefix: &mut Vec<T>,
+                                   prefix_sum: WideSum<T>, best: &mut Best<T>) {
+    if k == 1 {
+        for n in &numbers[start..] {
+            consider(prefix, &[*n], prefix_sum.add(*n), target, best);
+        }
+    } else if k == 2 {
+        let mut left = start;
+        let mut right = numbers.len() - 1;
+
+        while left < right {
+            let sum = prefix_sum.add(numbers[left]).add(numbers[right]);
+            consider(prefix, &[numbers[left], numbers[right]], sum, target, best);
+
+            if sum == target {
+                break;
+            } else if sum < target {
+                left += 1;
+            } else {
+                right -= 1;
+            }
+        }
+    } else {
+        for i in start..=(numbers.len() - k) {
+            if i > start && numbers[i] == numbers[i - 1] {
+                continue;
+            }
+
+            prefix.push(numbers[i]);
+            closest_with_prefix(numbers, i + 1, k - 1, target, prefix, prefix_sum.add(numbers[i]), best);
+            prefix.pop();
+        }
+    }
+}
+
+fn consider<T: Integer>(prefix: &[T], rest: &[T], sum: WideSum<T>, target: WideSum<T>, best: &mut Best<T>) {
+    let distance = if sum >= target { sum.sub(target) } else { target.sub(sum) };
+
+    let is_better = match best {
+        Some((best_distance, best_sum, _)) => (distance, sum) < (*best_distance, *best_sum),
+        None => true,
+    };
+
+    if is_better {
+        *best = Some((distance, sum, prefix.iter().chain(rest.iter()).copied().collect()));
+    }
+}
+
+fn in_range_with_prefix<T: Integer>(numbers: &[T], start: usize, k: usize, range: &RangeInclusive<WideSum<T>>,
+                                    prefix: &mut Vec<T>, prefix_sum: WideSum<T>, solutions: &mut HashSet<Vec<T>>) {
+    for i in start..=(numbers.len() - k) {
+        if i > start && numbers[i] == numbers[i - 1] {
+            continue;
+        }
+
+        let sum = prefix_sum.add(numbers[i]);
+
+        if k == 1 {
+            // Everything after this is at least as big, so we can stop as soon as we overshoot
+            if sum > *range.end() {
+                break;
+            }
+
+            if range.contains(&sum) {
+                let mut solution = prefix.clone();
+                solution.push(numbers[i]);
+
+                solutions.insert(solution);
+            }
+        } else {
+            prefix.push(numbers[i]);
+            in_range_with_prefix(numbers, i + 1, k - 1, range, prefix, sum, solutions);
+            prefix.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use crate::{KSumError, SortedSlice};
+    use crate::closest;
+
+    #[test]
+    fn closest_ksum() {
+        let mut numbers = vec![-1, 2, 1, -4];
+        let numbers = SortedSlice::sort(&mut numbers);
+
+        assert_eq!(vec![-1, 1, 2], closest::closest_ksum(numbers, 3, 1).unwrap());
+        assert_eq!(vec![1, 2], closest::closest_ksum(numbers, 2, 3).unwrap());
+        assert_eq!(vec![2], closest::closest_ksum(numbers, 1, 100).unwrap());
+        assert_eq!(vec![-4, -1, 1, 2], closest::closest_ksum(numbers, 4, 0).unwrap());
+    }
+
+    #[test]
+    fn closest_ksum_ties() {
+        assert_eq!(vec![1], closest::closest_ksum(SortedSlice::new(&[1, 3]).unwrap(), 1, 2).unwrap());
+        assert_eq!(vec![1, 4], closest::closest_ksum(SortedSlice::new(&[1, 4, 6]).unwrap(), 2, 6).unwrap());
+    }
+
+    #[test]
+    fn closest_ksum_errors() {
+        assert_eq!(Err(KSumError::TooFewNumbers { k: 3, len: 2 }), closest::closest_ksum(SortedSlice::new(&[1, 3]).unwrap(), 3, 2));
+    }
+
+    #[test]
+    fn overflow() {
+        // The distance from the target doesn't fit in an i8, but it's still the only candidate
+        assert_eq!(vec![i8::MAX], closest::closest_ksum(SortedSlice::new(&[i8::MAX]).unwrap(), 1, -1).unwrap());
+
+        let numbers = SortedSlice::new(&[1u64, 2, u64::MAX]).unwrap();
+
+        assert_eq!(vec![1, 2], closest::closest_ksum(numbers, 2, 3).unwrap());
+        assert_eq!(vec![1, 2], closest::closest_ksum(numbers, 2, 0).unwrap());
+        assert_eq!(vec![1, u64::MAX], closest::closest_ksum(numbers, 2, u64::MAX).unwrap());
+
+        let mut expected = HashSet::new();
+        expected.insert(vec![1, 2]);
+
+        assert_eq!(expected, closest::ksum_in_range(numbers, 2, 0..=3).unwrap());
+        assert_eq!(expected, closest::ksum_in_range(numbers, 2, 0..=u64::MAX).unwrap());
+    }
+
+    #[test]
+    fn ksum_in_range() {
+        let numbers = SortedSlice::new(&[1, 2, 3, 4, 5]).unwrap();
+
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![1, 4]);
+            expected.insert(vec![1, 5]);
+            expected.insert(vec![2, 3]);
+            expected.insert(vec![2, 4]);
+
+            assert_eq!(expected, closest::ksum_in_range(numbers, 2, 5..=6).unwrap());
+        }
+
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![1, 2, 3]);
+            expected.insert(vec![1, 2, 4]);
+
+            assert_eq!(expected, closest::ksum_in_range(numbers, 3, 6..=7).unwrap());
+        }
+
+        assert!(closest::ksum_in_range(numbers, 2, 20..=30).unwrap().is_empty());
+    }
+
+    #[test]
+    fn ksum_in_range_duplicates() {
+        let mut expected = HashSet::new();
+        expected.insert(vec![1, 1]);
+        expected.insert(vec![1, 2]);
+
+        assert_eq!(expected, closest::ksum_in_range(SortedSlice::new(&[1, 1, 2, 2]).unwrap(), 2, 2..=3).unwrap());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{advance_left, advance_right, check_k, Integer, KSumError, SortedSlice, WideSum};
+
+/// Lazily produces the distinct k-sum solutions in a sorted list of numbers, in lexicographic order.
+///
+/// Each solution is found only when it's requested, so taking the first few solutions from a large
+/// input is much cheaper than collecting all of them. Sums too big (or too small) for `T` are just
+/// treated as missing the target, so the search never fails partway through.
+pub struct KSumIter<'a, T: Integer> {
+    numbers: &'a [T],
+    k: usize,
+    target: WideSum<T>,
+    base_sum: WideSum<T>,
+
+    // Positions of the outer k - 2 chosen numbers; the last two are found with the two-pointer
+    // search between `left` and `right`.
+    prefix: Vec<usize>,
+    prefix_sum: WideSum<T>,
+    left: usize,
+    right: usize,
+
+    state: State,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum State {
+    Start,
+    Searching,
+    Done,
+}
+
+impl<'a, T: Integer> KSumIter<'a, T> {
+    pub fn new(numbers: SortedSlice<'a, T>, k: usize, target: T) -> Result<Self, KSumError> {
+        Self::with_base_sum(numbers, k, target, T::zero())
+    }
+
+    // Starts every sum from `base_sum` instead of zero, as if some numbers had already been chosen
+    pub(crate) fn with_base_sum(numbers: SortedSlice<'a, T>, k: usize, target: T, base_sum: T) -> Result<Self, KSumError> {
+        check_k(numbers.len(), k)?;
+
+        Ok(KSumIter {
+            numbers: numbers.as_slice(),
+            k,
+            target: WideSum::new(target),
+            base_sum: WideSum::new(base_sum),
+            prefix: (0..k.saturating_sub(2)).collect(),
+            prefix_sum: WideSum::new(T::zero()),
+            left: 0,
+            right: 0,
+            state: State::Start,
+        })
+    }
+
+    fn search(&mut self) -> Option<Vec<T>> {
+        if self.k == 1 {
+            while self.left < self.numbers.len() {
+                let position = self.left;
+                self.left += 1;
+
+                if position > 0 && self.numbers[position - 1] == self.numbers[position] {
+                    continue;
+                }
+
+                if self.prefix_sum.add(self.numbers[position]) == self.target {
+                    return Some(vec![self.numbers[position]]);
+                }
+            }
+        } else {
+            while self.left < self.right {
+                let sum = self.prefix_sum.add(self.numbers[self.left]).add(self.numbers[self.right]);
+
+                if sum == self.target {
+                    let mut solution: Vec<T> = self.prefix.iter().map(|position| self.numbers[*position]).collect();
+                    solution.push(self.numbers[self.left]);
+                    solution.push(self.numbers[self.right]);
+
+                    advance_left(self.numbers, &mut self.left);
+                    advance_right(self.numbers, &mut self.right);
+
+                    return Some(solution);
+                } else if sum < self.target {
+                    advance_left(self.numbers, &mut self.left);
+                } else {
+                    advance_right(self.numbers, &mut self.right);
+                }
+            }
+        }
+
+        None
+    }
+
+    // Moves the outer positions to the next distinct combination of values, much like an odometer;
+    // returns false when every combination has been visited.
+    fn advance_prefix(&mut self) -> bool {
+        for level in (0..self.prefix.len()).rev() {
+            let value = self.numbers[self.prefix[level]];
+            let mut position = self.prefix[level] + 1;
+
+            while position < self.numbers.len() && self.numbers[position] == value {
+                position += 1;
+            }
+
+            // Leave room for every number that still needs to be chosen after this one
+            if position <= self.numbers.len() - (self.k - level) {
+                self.prefix[level] = position;
+
+                for deeper_level in (level + 1)..self.prefix.len() {
+                    self.prefix[deeper_level] = self.prefix[deeper_level - 1] + 1;
+                }
+
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl<'a, T: Integer> Iterator for KSumIter<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                State::Done => return None,
+
+                State::Start => {
+                    self.prefix_sum = self.prefix.iter()
+                        .fold(self.base_sum, |sum, position| sum.add(self.numbers[*position]));
+
+                    self.left = self.prefix.last().map_or(0, |position| position + 1);
+                    self.right = self.numbers.len() - 1;
+                    self.state = State::Searching;
+                },
+
+                State::Searching => {
+                    match self.search() {
+                        Some(solution) => return Some(solution),
+                        None => {
+                            self.state = if self.advance_prefix() { State::Start } else { State::Done };
+                        },
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{KSumIter, SortedSlice};
+
+    #[test]
+    fn sorted_order() {
+        let solutions: Vec<Vec<i32>> = KSumIter::new(SortedSlice::new(&[1, 1, 2, 2, 3]).unwrap(), 3, 5).unwrap().collect();
+
+        assert_eq!(vec![vec![1, 1, 3], vec![1, 2, 2]], solutions);
+
+        let solutions: Vec<Vec<i32>> = KSumIter::new(SortedSlice::new(&[-3, -1, 0, 1, 2, 4, 5]).unwrap(), 4, 3).unwrap().collect();
+
+        assert_eq!(vec![vec![-3, -1, 2, 5], vec![-3, 0, 1, 5], vec![-3, 0, 2, 4]], solutions);
+    }
+
+    #[test]
+    fn take_first() {
+        let mut iter = KSumIter::new(SortedSlice::new(&[1, 2, 2, 2, 3, 4]).unwrap(), 2, 5).unwrap();
+
+        assert_eq!(Some(vec![1, 4]), iter.next());
+        assert_eq!(Some(vec![2, 3]), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn one_sum() {
+        let solutions: Vec<Vec<i32>> = KSumIter::new(SortedSlice::new(&[1, 2, 2, 2, 3, 4]).unwrap(), 1, 2).unwrap().collect();
+
+        assert_eq!(vec![vec![2]], solutions);
+    }
+
+    #[test]
+    fn continues_past_overflow() {
+        let mut iter = KSumIter::new(SortedSlice::new(&[1u8, 2, 200, 250]).unwrap(), 3, 203).unwrap();
+
+        assert_eq!(Some(vec![1, 2, 200]), iter.next());
+        assert_eq!(None, iter.next());
+
+        let mut iter = KSumIter::new(SortedSlice::new(&[1u64, 2, u64::MAX]).unwrap(), 2, 3).unwrap();
+
+        assert_eq!(Some(vec![1, 2]), iter.next());
+        assert_eq!(None, iter.next());
+    }
+}
@@ -1,57 +1,247 @@
 use std::collections::HashSet;
+use std::error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
 
-pub fn ksum(numbers: &[i64], k: usize, target: i64) -> HashSet<Vec<i64>> {
-    assert!(k > 0, "K must be positive");
-    assert!(numbers.len() >= k, "Number list must be longer than k");
+mod closest;
+mod iter;
+mod options;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod sorted;
+mod strategy;
 
-    let mut solutions = HashSet::new();
+pub use closest::{closest_ksum, ksum_in_range};
+pub use iter::KSumIter;
+pub use options::{ksum_with_options, KSumOptions};
+#[cfg(feature = "parallel")]
+pub use parallel::par_ksum;
+pub use sorted::SortedSlice;
+pub use strategy::{ksum_unsorted, ksum_with_strategy, KSumStrategy};
 
+pub trait Integer: Copy + Ord + Hash + Debug + Send + Sync {
+    fn zero() -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+    fn overflowing_sub(self, other: Self) -> (Self, bool);
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn overflowing_add(self, other: Self) -> (Self, bool) {
+                    <$t>::overflowing_add(self, other)
+                }
+
+                fn overflowing_sub(self, other: Self) -> (Self, bool) {
+                    <$t>::overflowing_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A running total that keeps going past either end of `T`'s range by counting how many times it
+/// has wrapped around. A partial sum can overflow even when the finished sum is representable (or is
+/// simply bigger than the target), so searches compare these rather than giving up.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+pub(crate) struct WideSum<T> {
+    // Wraps come first so the derived ordering matches the ordering of the true sums
+    wraps: i64,
+    low: T,
+}
+
+impl<T: Integer> WideSum<T> {
+    pub(crate) fn new(value: T) -> Self {
+        WideSum { wraps: 0, low: value }
+    }
+
+    pub(crate) fn add(self, n: T) -> Self {
+        let (low, overflowed) = self.low.overflowing_add(n);
+        let carry = if !overflowed { 0 } else if n > T::zero() { 1 } else { -1 };
+
+        WideSum { wraps: self.wraps + carry, low }
+    }
+
+    pub(crate) fn sub(self, other: Self) -> Self {
+        let (low, overflowed) = self.low.overflowing_sub(other.low);
+        let borrow = if !overflowed { 0 } else if other.low > T::zero() { 1 } else { -1 };
+
+        WideSum { wraps: self.wraps - other.wraps - borrow, low }
+    }
+
+    /// The sum as a plain `T`, if it fits.
+    pub(crate) fn value(self) -> Option<T> {
+        if self.wraps == 0 { Some(self.low) } else { None }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum KSumError {
+    NonPositiveK,
+    TooFewNumbers { k: usize, len: usize },
+    Unsorted,
+}
+
+impl fmt::Display for KSumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KSumError::NonPositiveK => write!(f, "K must be positive"),
+            KSumError::TooFewNumbers { k, len } => write!(f, "Can't choose {} numbers from a list of {}", k, len),
+            KSumError::Unsorted => write!(f, "Number list must be sorted"),
+        }
+    }
+}
+
+impl error::Error for KSumError {}
+
+/// Finds the distinct k-sum solutions in a sorted list of numbers. Sums too big or too small for `T`
+/// just miss the target, so the only errors are for a `k` that doesn't fit the list.
+pub fn ksum<T: Integer>(numbers: SortedSlice<T>, k: usize, target: T) -> Result<HashSet<Vec<T>>, KSumError> {
+    ksum_with_options(numbers, k, target, &KSumOptions::default())
+}
+
+pub fn ksum_indices<T: Integer>(numbers: &[T], k: usize, target: T) -> Result<Vec<Vec<usize>>, KSumError> {
+    check_k(numbers.len(), k)?;
+
+    // Sort a permutation of the caller's indices rather than the numbers themselves so we can map
+    // positions in the sorted list back to positions in the original list.
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    order.sort_by_key(|i| numbers[*i]);
+
+    let sorted: Vec<T> = order.iter().map(|i| numbers[*i]).collect();
+
+    let mut solutions = Vec::new();
+    ksum_positions(&sorted, 0, k, WideSum::new(target), &mut Vec::with_capacity(k), WideSum::new(T::zero()), &mut solutions);
+
+    let mut solutions: Vec<Vec<usize>> = solutions.into_iter()
+        .map(|positions| {
+            let mut indices: Vec<usize> = positions.iter().map(|position| order[*position]).collect();
+            indices.sort_unstable();
+
+            indices
+        })
+        .collect();
+
+    solutions.sort();
+
+    Ok(solutions)
+}
+
+// Unlike ksum_with_prefix, this doesn't skip over runs of equal numbers; every combination of
+// positions that produces the target is a distinct solution.
+fn ksum_positions<T: Integer>(sorted: &[T], start: usize, k: usize, target: WideSum<T>, prefix: &mut Vec<usize>,
+                              prefix_sum: WideSum<T>, solutions: &mut Vec<Vec<usize>>) {
     if k == 1 {
-        for n in numbers {
-            if *n == target {
-                solutions.insert(vec![*n]);
+        for (position, n) in sorted.iter().enumerate().skip(start) {
+            if prefix_sum.add(*n) == target {
+                let mut solution = prefix.clone();
+                solution.push(position);
+
+                solutions.push(solution);
             }
         }
     } else if k == 2 {
-        let mut left = 0;
-        let mut right = numbers.len() - 1;
+        let mut left = start;
+        let mut right = sorted.len() - 1;
 
         while left < right {
-            if numbers[left] + numbers[right] == target {
-                solutions.insert(vec![numbers[left], numbers[right]]);
+            let sum = prefix_sum.add(sorted[left]).add(sorted[right]);
+
+            if sum == target {
+                if sorted[left] == sorted[right] {
+                    // Everything between the two pointers is the same number, so any pair works
+                    for i in left..right {
+                        for j in (i + 1)..=right {
+                            let mut solution = prefix.clone();
+                            solution.push(i);
+                            solution.push(j);
+
+                            solutions.push(solution);
+                        }
+                    }
+
+                    break;
+                }
+
+                let mut left_end = left + 1;
+
+                while sorted[left_end] == sorted[left] {
+                    left_end += 1;
+                }
+
+                let mut right_start = right - 1;
+
+                while sorted[right_start] == sorted[right] {
+                    right_start -= 1;
+                }
+
+                for i in left..left_end {
+                    for j in (right_start + 1)..=right {
+                        let mut solution = prefix.clone();
+                        solution.push(i);
+                        solution.push(j);
 
-                advance_left(numbers, &mut left);
-                advance_right(numbers, &mut right);
-            } else if numbers[left] + numbers[right] < target {
-                advance_left(numbers, &mut left);
+                        solutions.push(solution);
+                    }
+                }
+
+                left = left_end;
+                right = right_start;
+            } else if sum < target {
+                left += 1;
             } else {
-                advance_right(numbers, &mut right);
+                right -= 1;
             }
         }
     } else {
-        for i in 0..=(numbers.len() - k) {
-            for mut solution in ksum(&numbers[(i + 1)..], k - 1, target - numbers[i]) {
-                solution.insert(0, numbers[i]);
-                solutions.insert(solution);
-            }
+        for i in start..=(sorted.len() - k) {
+            prefix.push(i);
+            ksum_positions(sorted, i + 1, k - 1, target, prefix, prefix_sum.add(sorted[i]), solutions);
+            prefix.pop();
         }
     }
+}
 
-    solutions
+fn check_k(len: usize, k: usize) -> Result<(), KSumError> {
+    if k == 0 {
+        Err(KSumError::NonPositiveK)
+    } else if len < k {
+        Err(KSumError::TooFewNumbers { k, len })
+    } else {
+        Ok(())
+    }
 }
 
-fn advance_left(numbers: &[i64], left: &mut usize) {
+fn advance_left<T: Integer>(numbers: &[T], left: &mut usize) {
     let initial_left_value = numbers[*left];
 
-    while numbers[*left] == initial_left_value && *left < numbers.len() {
+    while *left < numbers.len() && numbers[*left] == initial_left_value {
         *left += 1;
     }
 }
 
-fn advance_right(numbers: &[i64], right: &mut usize) {
+fn advance_right<T: Integer>(numbers: &[T], right: &mut usize) {
     let initial_right_value = numbers[*right];
 
-    while numbers[*right] == initial_right_value && *right != 0 {
+    while *right != 0 && numbers[*right] == initial_right_value {
         *right -= 1;
     }
 }
@@ -59,18 +249,46 @@ fn advance_right(numbers: &[i64], right: &mut usize) {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use crate::ksum::ksum;
+    use crate::{ksum, ksum_indices, Integer, KSumError, SortedSlice};
+
+    fn sorted<T: Integer>(numbers: &[T]) -> SortedSlice<'_, T> {
+        SortedSlice::new(numbers).unwrap()
+    }
 
     #[test]
-    #[should_panic]
     fn non_positive_k() {
-        ksum(vec![1, 2, 3].as_mut(), 0, 0);
+        assert_eq!(Err(KSumError::NonPositiveK), ksum(sorted(&[1, 2, 3]), 0, 0));
+        assert_eq!(Err(KSumError::NonPositiveK), ksum_indices(&[1, 2, 3], 0, 0));
     }
 
     #[test]
-    #[should_panic]
-    fn undersized_vec_panic() {
-        ksum(vec![1].as_mut(), 2, 0);
+    fn undersized_vec() {
+        assert_eq!(Err(KSumError::TooFewNumbers { k: 2, len: 1 }), ksum(sorted(&[1]), 2, 0));
+        assert_eq!(Err(KSumError::TooFewNumbers { k: 2, len: 1 }), ksum_indices(&[1], 2, 0));
+    }
+
+    #[test]
+    fn trailing_duplicates() {
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![2, 2]);
+
+            assert_eq!(expected, ksum(sorted(&[2, 2]), 2, 4).unwrap());
+        }
+
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![3]);
+
+            assert_eq!(expected, ksum(sorted(&[1, 3, 3]), 1, 3).unwrap());
+        }
+
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![1, 3, 3]);
+
+            assert_eq!(expected, ksum(sorted(&[1, 3, 3]), 3, 7).unwrap());
+        }
     }
 
     #[test]
@@ -79,10 +297,10 @@ mod tests {
             let mut expected = HashSet::new();
             expected.insert(vec![4]);
 
-            assert_eq!(expected, ksum(&[1, 2, 2, 2, 3, 4], 1, 4));
+            assert_eq!(expected, ksum(sorted(&[1, 2, 2, 2, 3, 4]), 1, 4).unwrap());
         }
 
-        assert_eq!(0, ksum(&[1, 2, 2, 2, 3, 4], 1, 5).len());
+        assert_eq!(0, ksum(sorted(&[1, 2, 2, 2, 3, 4]), 1, 5).unwrap().len());
     }
 
     #[test]
@@ -92,17 +310,17 @@ mod tests {
             expected.insert(vec![1, 4]);
             expected.insert(vec![2, 3]);
 
-            assert_eq!(expected, ksum(&[1, 2, 2, 2, 3, 4], 2, 5));
+            assert_eq!(expected, ksum(sorted(&[1, 2, 2, 2, 3, 4]), 2, 5).unwrap());
         }
 
         {
             let mut expected = HashSet::new();
             expected.insert(vec![1, 2]);
 
-            assert_eq!(expected, ksum(&[1, 2], 2, 3));
+            assert_eq!(expected, ksum(sorted(&[1, 2]), 2, 3).unwrap());
         }
 
-        assert_eq!(0, ksum(&[1, 2, 2, 2, 3, 4], 2, 12).len());
+        assert_eq!(0, ksum(sorted(&[1, 2, 2, 2, 3, 4]), 2, 12).unwrap().len());
     }
 
     #[test]
@@ -112,16 +330,69 @@ mod tests {
             expected.insert(vec![1, 1, 3]);
             expected.insert(vec![1, 2, 2]);
 
-            assert_eq!(expected, ksum(&[1, 1, 2, 2, 3], 3, 5));
+            assert_eq!(expected, ksum(sorted(&[1, 1, 2, 2, 3]), 3, 5).unwrap());
         }
 
         {
             let mut expected = HashSet::new();
             expected.insert(vec![1, 2, 3]);
 
-            assert_eq!(expected, ksum(&[1, 2, 3], 3, 6));
+            assert_eq!(expected, ksum(sorted(&[1, 2, 3]), 3, 6).unwrap());
+        }
+
+        assert_eq!(0, ksum(sorted(&[1, 1, 2, 2, 3]), 3, 12).unwrap().len());
+    }
+
+    #[test]
+    fn generic_types() {
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![2, u64::MAX - 2]);
+
+            assert_eq!(expected, ksum(sorted(&[2u64, 7, u64::MAX - 2]), 2, u64::MAX).unwrap());
         }
 
-        assert_eq!(0, ksum(&[1, 1, 2, 2, 3], 3, 12).len());
+        {
+            let mut expected = HashSet::new();
+            expected.insert(vec![-(1i128 << 100), 1, 1 << 100]);
+
+            assert_eq!(expected, ksum(sorted(&[-(1i128 << 100), -3, 1, 1 << 100]), 3, 1).unwrap());
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        // Sums too big to represent are just bigger than the target
+        assert!(ksum(sorted(&[1i64, i64::MAX]), 2, 0).unwrap().is_empty());
+        assert!(ksum(sorted(&[100u8, 120, 140]), 3, 255).unwrap().is_empty());
+
+        let mut expected = HashSet::new();
+        expected.insert(vec![1, 2]);
+
+        assert_eq!(expected, ksum(sorted(&[1u64, 2, u64::MAX]), 2, 3).unwrap());
+        assert_eq!(vec![vec![0, 1]], ksum_indices(&[1u64, 2, u64::MAX], 2, 3).unwrap());
+
+        // Partial sums can overflow and come back into range
+        let mut expected = HashSet::new();
+        expected.insert(vec![-100i8, -100, 100, 100]);
+
+        assert_eq!(expected, ksum(sorted(&[-100i8, -100, 100, 100]), 4, 0).unwrap());
+        assert_eq!(vec![vec![0, 1, 2, 3]], ksum_indices(&[-100i8, -100, 100, 100], 4, 0).unwrap());
+    }
+
+    #[test]
+    fn two_sum_indices() {
+        assert_eq!(vec![vec![0, 3]], ksum_indices(&[1721, 979, 366, 299, 675, 1456], 2, 2020).unwrap());
+        assert_eq!(vec![vec![0, 1], vec![2, 3]], ksum_indices(&[3, 1, 2, 2, 4], 2, 4).unwrap());
+        assert_eq!(vec![vec![0, 1], vec![0, 3], vec![1, 3]], ksum_indices(&[5, 5, 1, 5], 2, 10).unwrap());
+        assert_eq!(vec![vec![0, 2], vec![0, 4], vec![2, 3], vec![3, 4]], ksum_indices(&[1, 9, 4, 1, 4], 2, 5).unwrap());
+
+        assert!(ksum_indices(&[3, 1, 2, 2, 4], 2, 12).unwrap().is_empty());
+    }
+
+    #[test]
+    fn three_sum_indices() {
+        assert_eq!(vec![vec![1, 2, 4]], ksum_indices(&[1721, 979, 366, 299, 675, 1456], 3, 2020).unwrap());
+        assert_eq!(vec![vec![0, 2, 3], vec![1, 2, 4], vec![1, 3, 4]], ksum_indices(&[3, 1, 2, 2, 4], 3, 7).unwrap());
     }
 }
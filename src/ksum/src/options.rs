@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+use std::cmp::min;
+use std::collections::HashSet;
+
+use crate::{check_k, Integer, KSumError, KSumIter, SortedSlice};
+
+/// Controls how many times each number may appear in a single solution.
+///
+/// By default, every number in the input may be used once, so a value that appears three times in
+/// the input may appear up to three times in a solution. With `allow_reuse`, every value may be
+/// used any number of times regardless of how often it appears in the input (as when making change
+/// from a set of coin denominations). `max_multiplicity` caps the number of times any single value
+/// may appear in a solution in either mode.
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+pub struct KSumOptions {
+    pub allow_reuse: bool,
+    pub max_multiplicity: Option<usize>,
+}
+
+pub fn ksum_with_options<T: Integer>(numbers: SortedSlice<T>, k: usize, target: T, options: &KSumOptions) -> Result<HashSet<Vec<T>>, KSumError> {
+    if !options.allow_reuse {
+        check_k(numbers.len(), k)?;
+    } else if k == 0 {
+        return Err(KSumError::NonPositiveK);
+    } else if numbers.is_empty() {
+        return Err(KSumError::TooFewNumbers { k, len: 0 });
+    }
+
+    // Each value appears in the expanded list exactly as many times as it may be used, which turns
+    // every mode into a plain k-sum over the expanded list.
+    let expanded = expand(numbers.as_slice(), k, options);
+
+    if expanded.len() < k {
+        return Ok(HashSet::new());
+    }
+
+    Ok(KSumIter::new(SortedSlice::new_unchecked(&expanded), k, target)?.collect())
+}
+
+fn expand<'a, T: Integer>(numbers: &'a [T], k: usize, options: &KSumOptions) -> Cow<'a, [T]> {
+    // No value can appear more than k times in a solution anyway
+    let max_multiplicity = min(options.max_multiplicity.unwrap_or(k), k);
+
+    if !options.allow_reuse && max_multiplicity == k {
+        return Cow::Borrowed(numbers);
+    }
+
+    let mut expanded = Vec::with_capacity(numbers.len());
+    let mut start = 0;
+
+    while start < numbers.len() {
+        let mut end = start + 1;
+
+        while end < numbers.len() && numbers[end] == numbers[start] {
+            end += 1;
+        }
+
+        let copies = if options.allow_reuse {
+            max_multiplicity
+        } else {
+            min(end - start, max_multiplicity)
+        };
+
+        expanded.extend(std::iter::repeat_n(numbers[start], copies));
+        start = end;
+    }
+
+    Cow::Owned(expanded)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use crate::{ksum, ksum_with_options, KSumError, KSumOptions, SortedSlice};
+
+    #[test]
+    fn default_matches_ksum() {
+        let numbers = SortedSlice::new(&[1, 1, 2, 2, 3]).unwrap();
+
+        for k in 1..=5 {
+            for target in 0..=12 {
+                assert_eq!(ksum(numbers, k, target), ksum_with_options(numbers, k, target, &KSumOptions::default()));
+            }
+        }
+    }
+
+    #[test]
+    fn allow_reuse() {
+        let numbers = SortedSlice::new(&[1, 2, 3]).unwrap();
+
+        let options = KSumOptions {
+            allow_reuse: true,
+            max_multiplicity: None,
+        };
+
+        let mut expected = HashSet::new();
+        expected.insert(vec![1, 2, 3]);
+        expected.insert(vec![2, 2, 2]);
+
+        assert_eq!(expected, ksum_with_options(numbers, 3, 6, &options).unwrap());
+
+        // Reuse means we can choose more numbers than there are in the input
+        let mut expected = HashSet::new();
+        expected.insert(vec![1, 1, 1, 3, 3]);
+        expected.insert(vec![1, 1, 2, 2, 3]);
+        expected.insert(vec![1, 2, 2, 2, 2]);
+
+        assert_eq!(expected, ksum_with_options(numbers, 5, 9, &options).unwrap());
+    }
+
+    #[test]
+    fn max_multiplicity() {
+        {
+            let options = KSumOptions {
+                allow_reuse: true,
+                max_multiplicity: Some(2),
+            };
+
+            let mut expected = HashSet::new();
+            expected.insert(vec![1, 2, 3]);
+
+            assert_eq!(expected, ksum_with_options(SortedSlice::new(&[1, 2, 3]).unwrap(), 3, 6, &options).unwrap());
+        }
+
+        {
+            let options = KSumOptions {
+                allow_reuse: false,
+                max_multiplicity: Some(1),
+            };
+
+            let numbers = SortedSlice::new(&[1, 1, 2, 2, 3]).unwrap();
+
+            assert!(ksum_with_options(numbers, 3, 5, &options).unwrap().is_empty());
+            assert!(ksum_with_options(numbers, 4, 6, &options).unwrap().is_empty());
+            assert_eq!(1, ksum_with_options(numbers, 3, 6, &options).unwrap().len());
+        }
+    }
+
+    #[test]
+    fn invalid_k() {
+        let numbers = SortedSlice::new(&[1, 2, 3]).unwrap();
+
+        let reuse = KSumOptions {
+            allow_reuse: true,
+            max_multiplicity: None,
+        };
+
+        assert_eq!(Err(KSumError::NonPositiveK), ksum_with_options(numbers, 0, 0, &reuse));
+        assert_eq!(Err(KSumError::TooFewNumbers { k: 4, len: 3 }), ksum_with_options(numbers, 4, 6, &KSumOptions::default()));
+        assert!(ksum_with_options(numbers, 4, 6, &reuse).is_ok());
+    }
+}
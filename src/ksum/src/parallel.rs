@@ -0,0 +1,116 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::{check_k, Integer, KSumError, KSumIter, SortedSlice};
+
+// The solutions that start with one particular first number, tagged with that number's job index
+type JobResult<T> = (usize, Result<Vec<Vec<T>>, KSumError>);
+
+/// Finds the distinct k-sum solutions in a sorted list of numbers using every available core.
+///
+/// The search is split on the first number of each solution, and each worker takes the next
+/// unclaimed first number whenever it finishes one, so cheap and expensive branches even out. The
+/// results are put back together in order, so they match what `KSumIter` would produce.
+pub fn par_ksum<T: Integer>(numbers: SortedSlice<T>, k: usize, target: T) -> Result<Vec<Vec<T>>, KSumError> {
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+
+    par_ksum_with_threads(numbers, k, target, threads)
+}
+
+fn par_ksum_with_threads<T: Integer>(numbers: SortedSlice<T>, k: usize, target: T, threads: usize) -> Result<Vec<Vec<T>>, KSumError> {
+    check_k(numbers.len(), k)?;
+
+    // With fewer than three numbers, the two-pointer search is already linear and not worth splitting
+    if k < 3 || threads < 2 {
+        return Ok(KSumIter::new(numbers, k, target)?.collect());
+    }
+
+    let firsts: Vec<usize> = (0..=(numbers.len() - k))
+        .filter(|i| *i == 0 || numbers[*i] != numbers[*i - 1])
+        .collect();
+
+    let next_job = AtomicUsize::new(0);
+
+    let mut results: Vec<JobResult<T>> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.min(firsts.len()))
+            .map(|_| scope.spawn(|| {
+                let mut results = Vec::new();
+
+                loop {
+                    let job = next_job.fetch_add(1, Ordering::Relaxed);
+
+                    if job >= firsts.len() {
+                        return results;
+                    }
+
+                    results.push((job, solutions_starting_at(numbers, firsts[job], k, target)));
+                }
+            }))
+            .collect();
+
+        workers.into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    // Jobs were numbered in order of their first number, so sorting by job restores the same
+    // lexicographic order as a sequential search no matter how the threads were scheduled
+    results.sort_unstable_by_key(|(job, _)| *job);
+
+    let mut solutions = Vec::new();
+
+    for (_, result) in results {
+        solutions.extend(result?);
+    }
+
+    Ok(solutions)
+}
+
+fn solutions_starting_at<T: Integer>(numbers: SortedSlice<T>, first: usize, k: usize, target: T) -> Result<Vec<Vec<T>>, KSumError> {
+    let rest = SortedSlice::new_unchecked(&numbers[(first + 1)..]);
+
+    Ok(KSumIter::with_base_sum(rest, k - 1, target, numbers[first])?
+        .map(|mut solution| {
+            solution.insert(0, numbers[first]);
+            solution
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{KSumError, KSumIter, SortedSlice};
+    use crate::parallel;
+
+    #[test]
+    fn matches_sequential() {
+        let mut numbers = vec![35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 20, -15, 0, 20, 15];
+        let numbers = SortedSlice::sort(&mut numbers);
+
+        for k in 1..=5 {
+            for target in [0, 20, 40, 127, 150, 219] {
+                let expected: Vec<Vec<i32>> = KSumIter::new(numbers, k, target).unwrap().collect();
+
+                for threads in [1, 2, 4, 64] {
+                    assert_eq!(expected, parallel::par_ksum_with_threads(numbers, k, target, threads).unwrap(),
+                               "k = {}, target = {}, threads = {}", k, target, threads);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn par_ksum() {
+        let numbers = SortedSlice::new(&[-3, -1, 0, 1, 2, 4, 5]).unwrap();
+
+        assert_eq!(vec![vec![-3, -1, 2, 5], vec![-3, 0, 1, 5], vec![-3, 0, 2, 4]],
+                   parallel::par_ksum(numbers, 4, 3).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Ok(vec![vec![1, 2, 200]]), parallel::par_ksum_with_threads(SortedSlice::new(&[1u8, 2, 200, 250]).unwrap(), 3, 203, 4));
+        assert_eq!(Err(KSumError::NonPositiveK), parallel::par_ksum_with_threads(SortedSlice::new(&[1, 2]).unwrap(), 0, 3, 4));
+        assert_eq!(Err(KSumError::TooFewNumbers { k: 3, len: 2 }), parallel::par_ksum_with_threads(SortedSlice::new(&[1, 2]).unwrap(), 3, 3, 4));
+    }
+}
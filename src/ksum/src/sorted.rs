@@ -0,0 +1,63 @@
+use std::ops::Deref;
+
+use crate::KSumError;
+
+/// A slice that's known to be sorted in ascending order, which the two-pointer search relies on.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct SortedSlice<'a, T> {
+    numbers: &'a [T],
+}
+
+impl<'a, T: Ord> SortedSlice<'a, T> {
+    pub fn new(numbers: &'a [T]) -> Result<Self, KSumError> {
+        if numbers.windows(2).all(|pair| pair[0] <= pair[1]) {
+            Ok(SortedSlice { numbers })
+        } else {
+            Err(KSumError::Unsorted)
+        }
+    }
+
+    pub fn sort(numbers: &'a mut [T]) -> Self {
+        numbers.sort_unstable();
+
+        SortedSlice { numbers }
+    }
+
+    // For slices that are sorted by construction, where checking again would be wasted effort
+    pub(crate) fn new_unchecked(numbers: &'a [T]) -> Self {
+        SortedSlice { numbers }
+    }
+
+    pub fn as_slice(&self) -> &'a [T] {
+        self.numbers
+    }
+}
+
+impl<'a, T> Deref for SortedSlice<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.numbers
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{KSumError, SortedSlice};
+
+    #[test]
+    fn new() {
+        assert_eq!(&[1, 2, 2, 3], SortedSlice::new(&[1, 2, 2, 3]).unwrap().as_slice());
+        assert_eq!(Err(KSumError::Unsorted), SortedSlice::new(&[1, 3, 2]));
+
+        let empty: [u32; 0] = [];
+        assert!(SortedSlice::new(&empty).is_ok());
+    }
+
+    #[test]
+    fn sort() {
+        let mut numbers = vec![3, 1, 2, 2];
+
+        assert_eq!(&[1, 2, 2, 3], SortedSlice::sort(&mut numbers).as_slice());
+    }
+}
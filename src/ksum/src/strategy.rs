@@ -0,0 +1,260 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{check_k, Integer, KSumError, KSumIter, SortedSlice, WideSum};
+
+// Meet-in-the-middle keeps every half-sized combination in memory at once, so only pick it when
+// there aren't too many of them.
+const MAX_MEET_IN_THE_MIDDLE_COMBINATIONS: usize = 1 << 20;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum KSumStrategy {
+    /// Sorts a copy of the input and runs the two-pointer search; O(n^(k-1)).
+    TwoPointer,
+
+    /// Finds complements in a hash set without sorting; O(n^(k-1)), but O(n) for k <= 2.
+    Hash,
+
+    /// Matches sums of combinations of k/2 numbers against sums of the remaining k - k/2;
+    /// O(n^(k - k/2)) time at the cost of O(n^(k/2)) memory.
+    MeetInTheMiddle,
+}
+
+impl KSumStrategy {
+    pub fn select(n: usize, k: usize) -> KSumStrategy {
+        match k {
+            1 | 2 => KSumStrategy::Hash,
+            3 => KSumStrategy::TwoPointer,
+            _ => {
+                if binomial(n, k / 2) <= MAX_MEET_IN_THE_MIDDLE_COMBINATIONS {
+                    KSumStrategy::MeetInTheMiddle
+                } else {
+                    KSumStrategy::TwoPointer
+                }
+            },
+        }
+    }
+}
+
+pub fn ksum_unsorted<T: Integer>(numbers: &[T], k: usize, target: T) -> Result<HashSet<Vec<T>>, KSumError> {
+    ksum_with_strategy(numbers, k, target, KSumStrategy::select(numbers.len(), k))
+}
+
+pub fn ksum_with_strategy<T: Integer>(numbers: &[T], k: usize, target: T, strategy: KSumStrategy) -> Result<HashSet<Vec<T>>, KSumError> {
+    check_k(numbers.len(), k)?;
+
+    match strategy {
+        KSumStrategy::TwoPointer => {
+            let mut sorted = numbers.to_vec();
+            Ok(KSumIter::new(SortedSlice::sort(&mut sorted), k, target)?.collect())
+        },
+
+        KSumStrategy::Hash => {
+            let mut solutions = HashSet::new();
+            hash_ksum(numbers, k, WideSum::new(target), &mut Vec::with_capacity(k), WideSum::new(T::zero()), &mut solutions);
+
+            Ok(solutions)
+        },
+
+        KSumStrategy::MeetInTheMiddle => meet_in_the_middle_ksum(numbers, k, target),
+    }
+}
+
+fn hash_ksum<T: Integer>(numbers: &[T], k: usize, target: WideSum<T>, prefix: &mut Vec<T>, prefix_sum: WideSum<T>,
+                         solutions: &mut HashSet<Vec<T>>) {
+    if k == 1 {
+        for n in numbers {
+            if prefix_sum.add(*n) == target {
+                solutions.insert(sorted_solution(prefix, &[*n]));
+            }
+        }
+    } else if k == 2 {
+        let mut seen = HashSet::with_capacity(numbers.len());
+
+        for n in numbers {
+            // If the complement can't be represented, there's no number that could complete the pair
+            let complement = target.sub(prefix_sum).sub(WideSum::new(*n)).value();
+
+            if let Some(complement) = complement {
+                if seen.contains(&complement) {
+                    solutions.insert(sorted_solution(prefix, &[complement, *n]));
+                }
+            }
+
+            seen.insert(*n);
+        }
+    } else {
+        for i in 0..=(numbers.len() - k) {
+            prefix.push(numbers[i]);
+            hash_ksum(&numbers[(i + 1)..], k - 1, target, prefix, prefix_sum.add(numbers[i]), solutions);
+            prefix.pop();
+        }
+    }
+}
+
+fn meet_in_the_middle_ksum<T: Integer>(numbers: &[T], k: usize, target: T) -> Result<HashSet<Vec<T>>, KSumError> {
+    let mut solutions = HashSet::new();
+
+    if k == 1 {
+        hash_ksum(numbers, k, WideSum::new(target), &mut Vec::new(), WideSum::new(T::zero()), &mut solutions);
+        return Ok(solutions);
+    }
+
+    let mut lower_halves: HashMap<WideSum<T>, Vec<Vec<usize>>> = HashMap::new();
+
+    for_each_combination(numbers.len(), k / 2, &mut |combination| {
+        lower_halves.entry(sum_of(numbers, combination)).or_default().push(combination.to_vec());
+    });
+
+    // Every set of k positions splits exactly one way into its k/2 lowest positions and the rest,
+    // so only pairing halves that don't interleave visits each candidate once.
+    for_each_combination(numbers.len(), k - (k / 2), &mut |upper_half| {
+        let needed = WideSum::new(target).sub(sum_of(numbers, upper_half));
+
+        if let Some(lower_halves) = lower_halves.get(&needed) {
+            for lower_half in lower_halves {
+                if lower_half.last() < upper_half.first() {
+                    let mut solution: Vec<T> = lower_half.iter().chain(upper_half.iter())
+                        .map(|position| numbers[*position])
+                        .collect();
+
+                    solution.sort_unstable();
+                    solutions.insert(solution);
+                }
+            }
+        }
+    });
+
+    Ok(solutions)
+}
+
+fn for_each_combination<F>(n: usize, size: usize, action: &mut F)
+    where F: FnMut(&[usize])
+{
+    let mut combination: Vec<usize> = (0..size).collect();
+
+    loop {
+        action(&combination);
+
+        // Find the rightmost position that can still move right, move it, and reset everything
+        // after it to follow immediately
+        match (0..size).rev().find(|i| combination[*i] < n - (size - i)) {
+            Some(i) => {
+                combination[i] += 1;
+
+                for j in (i + 1)..size {
+                    combination[j] = combination[j - 1] + 1;
+                }
+            },
+            None => return,
+        }
+    }
+}
+
+fn sum_of<T: Integer>(numbers: &[T], positions: &[usize]) -> WideSum<T> {
+    positions.iter().fold(WideSum::new(T::zero()), |sum, position| sum.add(numbers[*position]))
+}
+
+fn sorted_solution<T: Integer>(prefix: &[T], rest: &[T]) -> Vec<T> {
+    let mut solution: Vec<T> = prefix.iter().chain(rest.iter()).copied().collect();
+    solution.sort_unstable();
+
+    solution
+}
+
+// Saturates at usize::MAX rather than overflowing
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+
+    (0..k).try_fold(1usize, |product, i| product.checked_mul(n - i).map(|product| product / (i + 1)))
+        .unwrap_or(usize::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ksum, ksum_unsorted, ksum_with_strategy, KSumError, KSumStrategy, SortedSlice};
+    use crate::strategy;
+
+    const STRATEGIES: [KSumStrategy; 3] = [KSumStrategy::TwoPointer, KSumStrategy::Hash, KSumStrategy::MeetInTheMiddle];
+
+    #[test]
+    fn select() {
+        assert_eq!(KSumStrategy::Hash, KSumStrategy::select(25, 1));
+        assert_eq!(KSumStrategy::Hash, KSumStrategy::select(25, 2));
+        assert_eq!(KSumStrategy::TwoPointer, KSumStrategy::select(25, 3));
+        assert_eq!(KSumStrategy::MeetInTheMiddle, KSumStrategy::select(200, 4));
+        assert_eq!(KSumStrategy::TwoPointer, KSumStrategy::select(100_000, 4));
+    }
+
+    #[test]
+    fn strategies_agree() {
+        let numbers = [35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 20, -15, 0];
+
+        let mut sorted = numbers.to_vec();
+        let sorted = SortedSlice::sort(&mut sorted);
+
+        for k in 1..=5 {
+            for target in [0, 20, 40, 127, 150, 219] {
+                let expected = ksum(sorted, k, target).unwrap();
+
+                for strategy in STRATEGIES {
+                    assert_eq!(expected, ksum_with_strategy(&numbers, k, target, strategy).unwrap(),
+                               "k = {}, target = {}, strategy = {:?}", k, target, strategy);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unsorted() {
+        let mut expected = std::collections::HashSet::new();
+        expected.insert(vec![15, 25]);
+        expected.insert(vec![-15, 55]);
+
+        assert_eq!(expected, ksum_unsorted(&[55, 25, -15, 15, 40], 2, 40).unwrap());
+    }
+
+    #[test]
+    fn hash_does_not_underflow() {
+        let mut expected = std::collections::HashSet::new();
+        expected.insert(vec![1u8, 2]);
+
+        assert_eq!(expected, ksum_with_strategy(&[200u8, 1, 2], 2, 3, KSumStrategy::Hash).unwrap());
+    }
+
+    #[test]
+    fn strategies_agree_past_overflow() {
+        let mut expected = std::collections::HashSet::new();
+        expected.insert(vec![1u64, 2]);
+
+        for strategy in STRATEGIES {
+            assert_eq!(expected, ksum_with_strategy(&[u64::MAX, 1, 2], 2, 3, strategy).unwrap(), "{:?}", strategy);
+            assert!(ksum_with_strategy(&[i8::MAX, 1, 2, 3], 4, 0, strategy).unwrap().is_empty(), "{:?}", strategy);
+        }
+
+        let mut expected = std::collections::HashSet::new();
+        expected.insert(vec![-100i8, -100, 100, 100]);
+
+        for strategy in STRATEGIES {
+            assert_eq!(expected, ksum_with_strategy(&[100, -100, 100, -100, 1], 4, 0, strategy).unwrap(), "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn invalid_k() {
+        for strategy in STRATEGIES {
+            assert_eq!(Err(KSumError::NonPositiveK), ksum_with_strategy(&[1, 2, 3], 0, 3, strategy));
+            assert_eq!(Err(KSumError::TooFewNumbers { k: 4, len: 3 }), ksum_with_strategy(&[1, 2, 3], 4, 3, strategy));
+        }
+    }
+
+    #[test]
+    fn binomial() {
+        assert_eq!(1, strategy::binomial(25, 0));
+        assert_eq!(25, strategy::binomial(25, 1));
+        assert_eq!(300, strategy::binomial(25, 2));
+        assert_eq!(0, strategy::binomial(2, 3));
+        assert_eq!(usize::MAX, strategy::binomial(usize::MAX, 2));
+    }
+}
@@ -0,0 +1,78 @@
+//! Solutions for Advent of Code 2020.
+//!
+//! Each day lives in its own `dayNN` module, which holds that day's domain modules (like
+//! `day07::bags`) and a `Solution` for the day's two puzzles. Every solved day is registered in
+//! `puzzles()`, which is what the `aoc` runner and the per-day binaries use.
+
+#[macro_use]
+extern crate lazy_static;
+
+use crate::solution::Puzzle;
+
+pub mod parse;
+pub mod profile;
+pub mod runner;
+pub mod solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+static PUZZLES: [(u8, &(dyn Puzzle + Sync)); 25] = [
+    (1, &day01::Day01),
+    (2, &day02::Day02),
+    (3, &day03::Day03),
+    (4, &day04::Day04),
+    (5, &day05::Day05),
+    (6, &day06::Day06),
+    (7, &day07::Day07),
+    (8, &day08::Day08),
+    (9, &day09::Day09),
+    (10, &day10::Day10),
+    (11, &day11::Day11),
+    (12, &day12::Day12),
+    (13, &day13::Day13),
+    (14, &day14::Day14),
+    (15, &day15::Day15),
+    (16, &day16::Day16),
+    (17, &day17::Day17),
+    (18, &day18::Day18),
+    (19, &day19::Day19),
+    (20, &day20::Day20),
+    (21, &day21::Day21),
+    (22, &day22::Day22),
+    (23, &day23::Day23),
+    (24, &day24::Day24),
+    (25, &day25::Day25),
+];
+
+/// Every day with a registered solution, in order.
+pub fn puzzles() -> impl Iterator<Item = (u8, &'static dyn Puzzle)> {
+    PUZZLES.iter().map(|(day, puzzle)| (*day, *puzzle as &dyn Puzzle))
+}
+
+pub fn puzzle(day: u8) -> Option<&'static dyn Puzzle> {
+    puzzles().find(|(candidate, _)| *candidate == day).map(|(_, puzzle)| puzzle)
+}
@@ -0,0 +1,138 @@
+use std::{error, fmt};
+use std::str::FromStr;
+
+/// Explains why a line of puzzle input couldn't be parsed and where. Lines and columns are counted
+/// from 1, and columns count characters rather than bytes.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Reports a problem with `offending`, which should be a slice of `line`; its position within the
+    /// line becomes the error's column. Parsers only ever see one line, so the line number starts out
+    /// as 1 and gets fixed up by whoever knows where the line came from (see `parse_lines`).
+    pub fn new(line: &str, offending: &str, message: &str) -> Self {
+        let start = (offending.as_ptr() as usize).checked_sub(line.as_ptr() as usize)
+            .filter(|start| start + offending.len() <= line.len() && line.is_char_boundary(*start))
+            .unwrap_or(0);
+
+        ParseError {
+            line: 1,
+            column: line[..start].chars().count() + 1,
+            text: String::from(offending),
+            message: String::from(message),
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {} (\"{}\")", self.line, self.column, self.message, self.text)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses every line of the input, reporting the line number of the first line that fails.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|err: ParseError| err.at_line(i + 1)))
+        .collect()
+}
+
+/// Splits the input into sections separated by blank lines, along with the number of lines that
+/// come before each section, so errors within a section can still report the right line.
+pub fn sections(input: &str) -> Vec<(usize, &str)> {
+    let mut sections = Vec::new();
+    let mut lines_before = 0;
+
+    for section in input.split("\n\n") {
+        sections.push((lines_before, section));
+        lines_before += section.lines().count() + 1;
+    }
+
+    sections
+}
+
+/// Like `parse_lines`, but for a section that starts after `lines_before` lines of the input.
+pub fn parse_section<T: FromStr<Err = ParseError>>(section: &str, lines_before: usize) -> Result<Vec<T>, ParseError> {
+    parse_lines(section).map_err(|err| {
+        let line = err.line + lines_before;
+        err.at_line(line)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use crate::parse::{self, ParseError};
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Digit(u32);
+
+    impl FromStr for Digit {
+        type Err = ParseError;
+
+        fn from_str(line: &str) -> Result<Self, Self::Err> {
+            let trimmed = line.trim();
+
+            trimmed.parse().map(Digit).map_err(|_| ParseError::new(line, trimmed, "Expected a digit"))
+        }
+    }
+
+    #[test]
+    fn new() {
+        let line = "mem[8] = eleven";
+
+        assert_eq!(ParseError { line: 1, column: 10, text: String::from("eleven"), message: String::from("Bad value") },
+                   ParseError::new(line, &line[9..], "Bad value"));
+
+        // Columns count characters, not bytes
+        let line = "é: x";
+        assert_eq!(4, ParseError::new(line, &line[4..], "Bad rule").column);
+
+        // Text that isn't part of the line is reported from the start of the line
+        assert_eq!(1, ParseError::new(line, &String::from("x"), "Bad rule").column);
+    }
+
+    #[test]
+    fn display() {
+        let line = "acc +x";
+        let err = ParseError::new(line, &line[4..], "Invalid value").at_line(12);
+
+        assert_eq!("line 12, column 5: Invalid value (\"+x\")", err.to_string());
+    }
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(Ok(vec![Digit(1), Digit(2)]), parse::parse_lines("1\n2\n"));
+
+        let err = parse::parse_lines::<Digit>("1\n2\n  x\n4").unwrap_err();
+
+        assert_eq!(3, err.line);
+        assert_eq!(3, err.column);
+        assert_eq!("x", err.text);
+    }
+
+    #[test]
+    fn sections() {
+        assert_eq!(vec![(0, "a\nb"), (3, "c"), (5, "d\ne\n")], parse::sections("a\nb\n\nc\n\nd\ne\n"));
+    }
+
+    #[test]
+    fn parse_section() {
+        let sections = parse::sections("1\n2\n\n3\nx\n");
+        let (lines_before, section) = sections[1];
+
+        assert_eq!(5, parse::parse_section::<Digit>(section, lines_before).unwrap_err().line);
+    }
+}
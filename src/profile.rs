@@ -0,0 +1,157 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and keeps track of how much memory is in use. Binaries that want
+/// memory numbers from `measure` have to install it with `#[global_allocator]`; everywhere else,
+/// allocation is reported as zero.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+fn record_allocation(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(allocated, Ordering::Relaxed);
+}
+
+/// Wall time and the most memory allocated at once (beyond what was already allocated beforehand)
+/// for one step of solving a puzzle.
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub peak_bytes: usize,
+}
+
+/// Measurements for each step of solving a puzzle.
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+pub struct Profile {
+    pub parse: Measurement,
+    pub part1: Measurement,
+    pub part2: Measurement,
+}
+
+impl Profile {
+    pub fn elapsed(&self) -> Duration {
+        self.parse.elapsed + self.part1.elapsed + self.part2.elapsed
+    }
+
+    pub fn peak_bytes(&self) -> usize {
+        self.parse.peak_bytes.max(self.part1.peak_bytes).max(self.part2.peak_bytes)
+    }
+}
+
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Measurement) {
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    let peak_bytes = PEAK.load(Ordering::Relaxed).saturating_sub(baseline);
+
+    (result, Measurement { elapsed, peak_bytes })
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+pub fn format_millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+    use crate::profile::{self, Measurement, Profile};
+
+    #[test]
+    fn measure() {
+        let (sum, measurement) = profile::measure(|| (1..=10).sum::<u32>());
+
+        assert_eq!(55, sum);
+
+        // Tests don't install the counting allocator
+        assert_eq!(0, measurement.peak_bytes);
+    }
+
+    #[test]
+    fn profile_totals() {
+        let profile = Profile {
+            parse: Measurement { elapsed: Duration::from_millis(1), peak_bytes: 100 },
+            part1: Measurement { elapsed: Duration::from_millis(2), peak_bytes: 300 },
+            part2: Measurement { elapsed: Duration::from_millis(3), peak_bytes: 200 },
+        };
+
+        assert_eq!(Duration::from_millis(6), profile.elapsed());
+        assert_eq!(300, profile.peak_bytes());
+    }
+
+    #[test]
+    fn format_bytes() {
+        assert_eq!("0 B", profile::format_bytes(0));
+        assert_eq!("1023 B", profile::format_bytes(1023));
+        assert_eq!("1.5 KiB", profile::format_bytes(1536));
+        assert_eq!("228.9 MiB", profile::format_bytes(240_000_000));
+    }
+
+    #[test]
+    fn format_millis() {
+        assert_eq!("1.500", profile::format_millis(Duration::from_micros(1500)));
+        assert_eq!("3753.842", profile::format_millis(Duration::from_micros(3_753_842)));
+    }
+}
@@ -0,0 +1,314 @@
+use std::{env, error, fs};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::profile::{self, Profile};
+use crate::solution::Answer;
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("data/day{:02}-input.txt", day))
+}
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Input {
+    /// Treats `-` as stdin and anything else as a file path.
+    pub fn from_path_arg(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Inline input from the command line, where a literal `\n` stands in for a line break so
+    /// multi-line inputs fit in a single argument.
+    pub fn from_input_string(arg: &str) -> Input {
+        Input::Inline(arg.replace("\\n", "\n"))
+    }
+
+    pub fn read(&self) -> Result<String, Box<dyn error::Error>> {
+        match self {
+            Input::File(path) => fs::read_to_string(path)
+                .map_err(|err| simple_error::simple_error!("Could not read {}: {}", path.display(), err).into()),
+
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)
+                    .map_err(|err| simple_error::simple_error!("Could not read stdin: {}", err))?;
+
+                Ok(input)
+            },
+
+            Input::Inline(input) => Ok(input.clone()),
+        }
+    }
+
+    /// Opens the input for reading a line at a time, for inputs too big to read all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, Box<dyn error::Error>> {
+        match self {
+            Input::File(path) => {
+                let file = fs::File::open(path)
+                    .map_err(|err| simple_error::simple_error!("Could not read {}: {}", path.display(), err))?;
+
+                Ok(Box::new(BufReader::new(file)))
+            },
+
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+            Input::Inline(input) => Ok(Box::new(io::Cursor::new(input.clone().into_bytes()))),
+        }
+    }
+}
+
+/// How answers get printed: as prose, or as one JSON object per day.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Result<Format, Box<dyn error::Error>> {
+        match arg {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => simple_error::bail!("Unknown format: {} (expected text or json)", arg),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub day: u8,
+    pub answers: (Answer, Answer),
+
+    // Measured while solving, not counting reading the input
+    pub profile: Profile,
+}
+
+impl Solved {
+    /// With `time`, the object also gets wall time for each step and the peak allocation.
+    pub fn to_json(&self, time: bool) -> String {
+        let mut json = format!("{{\"day\":{},\"part1\":{},\"part2\":{},\"elapsed_ms\":{}",
+                               self.day, self.answers.0.to_json(), self.answers.1.to_json(),
+                               profile::format_millis(self.profile.elapsed()));
+
+        if time {
+            json.push_str(&format!(",\"parse_ms\":{},\"part1_ms\":{},\"part2_ms\":{},\"peak_bytes\":{}",
+                                   profile::format_millis(self.profile.parse.elapsed),
+                                   profile::format_millis(self.profile.part1.elapsed),
+                                   profile::format_millis(self.profile.part2.elapsed),
+                                   self.profile.peak_bytes()));
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+pub fn solve(day: u8, input: &Input) -> Result<Solved, Box<dyn error::Error>> {
+    let puzzle = crate::puzzle(day)
+        .ok_or_else(|| simple_error::simple_error!("No solution for day {}", day))?;
+
+    let (answers, profile) = puzzle.profile(&input.read()?)?;
+
+    Ok(Solved { day, answers, profile })
+}
+
+pub fn print_solved(solved: &Solved, format: Format, time: bool) {
+    match format {
+        Format::Text => {
+            println!("Day {}, part 1: {}", solved.day, solved.answers.0);
+            println!("Day {}, part 2: {}", solved.day, solved.answers.1);
+
+            if time {
+                let steps = [("parse", &solved.profile.parse), ("part 1", &solved.profile.part1), ("part 2", &solved.profile.part2)];
+
+                for (step, measurement) in steps.iter() {
+                    println!("  {:<7} {:>10} ms, peak {}", format!("{}:", step),
+                             profile::format_millis(measurement.elapsed), profile::format_bytes(measurement.peak_bytes));
+                }
+            }
+        },
+
+        Format::Json => println!("{}", solved.to_json(time)),
+    }
+}
+
+/// A table of how long each day took and how much memory it needed, with a total at the bottom.
+pub fn format_summary(solved: &[Solved]) -> String {
+    let mut table = format!("{:>3}  {:>10}  {:>11}  {:>11}  {:>10}  {:>11}\n",
+                            "Day", "Parse (ms)", "Part 1 (ms)", "Part 2 (ms)", "Total (ms)", "Peak memory");
+
+    for day in solved {
+        table.push_str(&format!("{:>3}  {:>10}  {:>11}  {:>11}  {:>10}  {:>11}\n",
+                                day.day,
+                                profile::format_millis(day.profile.parse.elapsed),
+                                profile::format_millis(day.profile.part1.elapsed),
+                                profile::format_millis(day.profile.part2.elapsed),
+                                profile::format_millis(day.profile.elapsed()),
+                                profile::format_bytes(day.profile.peak_bytes())));
+    }
+
+    let total: Duration = solved.iter().map(|day| day.profile.elapsed()).sum();
+    let peak = solved.iter().map(|day| day.profile.peak_bytes()).max().unwrap_or(0);
+
+    table.push_str(&format!("{:>3}  {:>10}  {:>11}  {:>11}  {:>10}  {:>11}\n",
+                            "All", "", "", "", profile::format_millis(total), profile::format_bytes(peak)));
+
+    table
+}
+
+/// Options for a single day's binary, which reads the day's input from the data directory unless
+/// told otherwise.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Options {
+    pub input: Input,
+    pub format: Format,
+}
+
+impl Options {
+    pub fn from_args(day: u8, args: &[String]) -> Result<Options, Box<dyn error::Error>> {
+        let usage = format!("Usage: day{:02} [--format (text | json)] [INPUT_FILE_PATH | - | --input-string INPUT]", day);
+
+        let mut input = None;
+        let mut format = Format::Text;
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let source = match arg.as_str() {
+                "--format" => {
+                    format = Format::from_arg(args.next().ok_or("--format needs a value")?)?;
+                    continue;
+                },
+                "--input-string" => Input::from_input_string(args.next().ok_or("--input-string needs a value")?),
+                _ if arg.starts_with("--") => simple_error::bail!("Unrecognized option: {}\n{}", arg, usage),
+                _ => Input::from_path_arg(arg),
+            };
+
+            if input.replace(source).is_some() {
+                simple_error::bail!(usage);
+            }
+        }
+
+        let input = input.unwrap_or_else(|| Input::File(default_input_path(day)));
+
+        Ok(Options { input, format })
+    }
+}
+
+/// The whole `main` for a single day's binary.
+pub fn run_day(day: u8) -> Result<(), Box<dyn error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = Options::from_args(day, &args)?;
+
+    print_solved(&solve(day, &options.input)?, options.format, false);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use crate::profile::{Measurement, Profile};
+    use crate::runner::{self, Format, Input, Options, Solved};
+    use crate::solution::Answer;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_input_path() {
+        assert_eq!(PathBuf::from("data/day07-input.txt"), runner::default_input_path(7));
+        assert_eq!(PathBuf::from("data/day24-input.txt"), runner::default_input_path(24));
+    }
+
+    #[test]
+    fn solve_unknown_day() {
+        assert!(runner::solve(26, &Input::File(runner::default_input_path(26))).is_err());
+    }
+
+    #[test]
+    fn solve_inline() {
+        let solved = runner::solve(25, &Input::from_input_string("5764801\\n17807724")).unwrap();
+
+        assert_eq!(25, solved.day);
+        assert_eq!((Answer::Integer(14897079), Answer::Unsolved), solved.answers);
+
+        assert!(runner::solve(7, &Input::File(PathBuf::from("data/missing.txt"))).is_err());
+    }
+
+    #[test]
+    fn from_input_string() {
+        assert_eq!(Input::Inline(String::from(".#.\n..#\n###")), Input::from_input_string(".#.\\n..#\\n###"));
+        assert_eq!(Input::Inline(String::from("0,3,6")), Input::from_input_string("0,3,6"));
+    }
+
+    #[test]
+    fn options_from_args() {
+        let text = Format::Text;
+
+        assert_eq!(Options { input: Input::File(PathBuf::from("data/day17-input.txt")), format: text },
+                   Options::from_args(17, &args(&[])).unwrap());
+
+        assert_eq!(Options { input: Input::File(PathBuf::from("cubes.txt")), format: text },
+                   Options::from_args(17, &args(&["cubes.txt"])).unwrap());
+
+        assert_eq!(Options { input: Input::Stdin, format: Format::Json },
+                   Options::from_args(17, &args(&["--format", "json", "-"])).unwrap());
+
+        assert_eq!(Options { input: Input::Inline(String::from(".#.\n..#\n###")), format: text },
+                   Options::from_args(17, &args(&["--input-string", ".#.\\n..#\\n###"])).unwrap());
+    }
+
+    #[test]
+    fn options_from_args_errors() {
+        assert!(Options::from_args(17, &args(&["--input-string"])).is_err());
+        assert!(Options::from_args(17, &args(&["--verbose"])).is_err());
+        assert!(Options::from_args(17, &args(&["--format", "xml"])).is_err());
+        assert!(Options::from_args(17, &args(&["a.txt", "b.txt"])).is_err());
+        assert!(Options::from_args(17, &args(&["a.txt", "--input-string", "abc"])).is_err());
+    }
+
+    fn solved(day: u8, millis: [u64; 3], peak_bytes: [usize; 3]) -> Solved {
+        let measurement = |i: usize| Measurement { elapsed: Duration::from_millis(millis[i]), peak_bytes: peak_bytes[i] };
+
+        Solved {
+            day,
+            answers: (Answer::Integer(306), Answer::Unsolved),
+            profile: Profile { parse: measurement(0), part1: measurement(1), part2: measurement(2) },
+        }
+    }
+
+    #[test]
+    fn solved_to_json() {
+        let solved = solved(22, [1, 2, 3], [1024, 0, 512]);
+
+        assert_eq!("{\"day\":22,\"part1\":306,\"part2\":null,\"elapsed_ms\":6.000}", solved.to_json(false));
+
+        assert_eq!("{\"day\":22,\"part1\":306,\"part2\":null,\"elapsed_ms\":6.000,\
+                    \"parse_ms\":1.000,\"part1_ms\":2.000,\"part2_ms\":3.000,\"peak_bytes\":1024}",
+                   solved.to_json(true));
+    }
+
+    #[test]
+    fn format_summary() {
+        let summary = runner::format_summary(&[solved(15, [1, 1500, 2250], [10, 2048, 240_000_000]), solved(22, [1, 2, 3], [0, 0, 0])]);
+        let lines: Vec<&str> = summary.lines().collect();
+
+        assert_eq!(4, lines.len());
+        assert!(lines[0].starts_with("Day"));
+        assert_eq!(" 15       1.000     1500.000     2250.000    3751.000    228.9 MiB", lines[1]);
+        assert_eq!(" 22       1.000        2.000        3.000       6.000          0 B", lines[2]);
+        assert_eq!("All                                          3757.000    228.9 MiB", lines[3]);
+    }
+}
@@ -0,0 +1,142 @@
+use std::{error, fmt};
+use std::convert::TryFrom;
+
+use crate::profile::{self, Profile};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+
+    // For parts that haven't been solved yet (or, like day 25's second part, don't have an answer)
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl Answer {
+    /// The answer as a JSON value; integers are numbers, text is a string and unsolved parts are
+    /// `null`.
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::Text(text) => json_string(text),
+            Answer::Unsolved => String::from("null"),
+        }
+    }
+}
+
+pub(crate) fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    // Anything too big to be an i64 can still be reported as text
+                    i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// A day's puzzle, split into parsing the input once and solving each part from the parsed input.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn error::Error>>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer, Box<dyn error::Error>>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer, Box<dyn error::Error>>;
+}
+
+/// Every `Solution` is also a `Puzzle`, which hides the input type so that solutions for different
+/// days can be kept together and run the same way.
+pub trait Puzzle {
+    fn solve(&self, input: &str) -> Result<(Answer, Answer), Box<dyn error::Error>>;
+
+    /// Like `solve`, but measures parsing and each part separately.
+    fn profile(&self, input: &str) -> Result<((Answer, Answer), Profile), Box<dyn error::Error>>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn solve(&self, input: &str) -> Result<(Answer, Answer), Box<dyn error::Error>> {
+        let input = self.parse(input)?;
+
+        Ok((self.part1(&input)?, self.part2(&input)?))
+    }
+
+    fn profile(&self, input: &str) -> Result<((Answer, Answer), Profile), Box<dyn error::Error>> {
+        let (input, parse) = profile::measure(|| self.parse(input));
+        let input = input?;
+
+        let (part1_answer, part1) = profile::measure(|| self.part1(&input));
+        let (part2_answer, part2) = profile::measure(|| self.part2(&input));
+
+        Ok(((part1_answer?, part2_answer?), Profile { parse, part1, part2 }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::Answer;
+
+    #[test]
+    fn answer_from() {
+        assert_eq!(Answer::Integer(-7), Answer::from(-7i32));
+        assert_eq!(Answer::Integer(42), Answer::from(42usize));
+        assert_eq!(Answer::Text(String::from("18446744073709551615")), Answer::from(u64::MAX));
+        assert_eq!(Answer::Text(String::from("abc")), Answer::from(String::from("abc")));
+    }
+
+    #[test]
+    fn to_json() {
+        assert_eq!("-7", Answer::Integer(-7).to_json());
+        assert_eq!("\"67384529\"", Answer::Text(String::from("67384529")).to_json());
+        assert_eq!("\"say \\\"hi\\\"\\n\\\\ \\u0007\"", Answer::Text(String::from("say \"hi\"\n\\ \u{7}")).to_json());
+        assert_eq!("null", Answer::Unsolved.to_json());
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!("42", Answer::Integer(42).to_string());
+        assert_eq!("abc", Answer::Text(String::from("abc")).to_string());
+        assert_eq!("unsolved", Answer::Unsolved.to_string());
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs;
+
+use advent_of_code_2020::runner::{self, Input};
+use advent_of_code_2020::solution::Answer;
+
+fn load_manifest() -> toml::value::Table {
+    let manifest: toml::Value = fs::read_to_string("data/answers.toml").unwrap().parse().unwrap();
+
+    manifest.as_table().cloned().unwrap()
+}
+
+fn expected_answer(answers: &toml::value::Table, part: &str) -> Answer {
+    match answers.get(part) {
+        Some(toml::Value::Integer(answer)) => Answer::Integer(*answer),
+        Some(toml::Value::String(answer)) => Answer::Text(answer.clone()),
+        Some(answer) => panic!("Unexpected answer type for {}: {}", part, answer),
+        None => Answer::Unsolved,
+    }
+}
+
+#[test]
+fn answers_match_manifest() {
+    let manifest = load_manifest();
+    let mut mismatches = Vec::new();
+
+    for (day, _) in advent_of_code_2020::puzzles() {
+        let key = format!("day{:02}", day);
+        let input_path = runner::default_input_path(day);
+
+        // Days without a checked-in input are covered by their examples instead
+        if !input_path.exists() {
+            assert!(!manifest.contains_key(&key), "{} has answers but no input at {}", key, input_path.display());
+            continue;
+        }
+
+        let answers = manifest.get(&key)
+            .and_then(toml::Value::as_table)
+            .unwrap_or_else(|| panic!("No answers for {} in data/answers.toml", key));
+
+        let expected = (expected_answer(answers, "part1"), expected_answer(answers, "part2"));
+        let actual = runner::solve(day, &Input::File(input_path)).unwrap().answers;
+
+        if expected != actual {
+            mismatches.push(format!("{}: expected ({}, {}), got ({}, {})", key, expected.0, expected.1, actual.0, actual.1));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "Answers changed:\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn manifest_only_lists_registered_days() {
+    let manifest = load_manifest();
+
+    for key in manifest.keys() {
+        let day: u8 = key.trim_start_matches("day").parse().unwrap();
+
+        assert!(advent_of_code_2020::puzzle(day).is_some(), "{} has answers but no registered solution", key);
+    }
+}
@@ -0,0 +1,80 @@
+use advent_of_code_2020::day07::bags::BagRules;
+use advent_of_code_2020::day08::console::{self, Exit, Instruction};
+use advent_of_code_2020::day14::mask;
+use advent_of_code_2020::parse;
+use advent_of_code_2020::solution::Answer;
+
+#[test]
+fn puzzle_registry() {
+    let days: Vec<u8> = advent_of_code_2020::puzzles().map(|(day, _)| day).collect();
+
+    assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(advent_of_code_2020::puzzle(7).is_some());
+    assert!(advent_of_code_2020::puzzle(26).is_none());
+}
+
+#[test]
+fn day01() {
+    let puzzle = advent_of_code_2020::puzzle(1).unwrap();
+
+    assert_eq!((Answer::Integer(514579), Answer::Integer(241861950)),
+               puzzle.solve("1721\n979\n366\n299\n675\n1456\n").unwrap());
+}
+
+#[test]
+fn day07() {
+    let rules: BagRules = [
+        "light red bags contain 1 bright white bag, 2 muted yellow bags.",
+        "dark orange bags contain 3 bright white bags, 4 muted yellow bags.",
+        "bright white bags contain 1 shiny gold bag.",
+        "muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.",
+        "shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.",
+        "dark olive bags contain 3 faded blue bags, 4 dotted black bags.",
+        "vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.",
+        "faded blue bags contain no other bags.",
+        "dotted black bags contain no other bags.",
+    ].iter().map(|line| line.to_string()).collect();
+
+    assert_eq!(4, rules.get_top_level_containers(&String::from("shiny gold")));
+    assert_eq!(32, rules.get_total_contained_bags(&String::from("shiny gold")));
+}
+
+#[test]
+fn day08() {
+    let instructions: Vec<Instruction> = parse::parse_lines("nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6")
+        .unwrap();
+
+    assert_eq!(Exit::Loop(5), console::eval(&instructions));
+    assert_eq!(8, console::get_acc_after_repair(&instructions));
+}
+
+#[test]
+fn day14() {
+    let instructions: Vec<mask::Instruction> = parse::parse_lines("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmem[7] = 101\nmem[8] = 0")
+        .unwrap();
+
+    assert_eq!(165, mask::eval_value_mask(instructions.into_iter()));
+}
+
+#[test]
+fn day19_no_matches() {
+    let puzzle = advent_of_code_2020::puzzle(19).unwrap();
+
+    assert_eq!((Answer::Integer(0), Answer::Integer(0)),
+               puzzle.solve("0: 1 1 1\n1: \"a\"\n\nab\nb\n").unwrap());
+}
+
+#[test]
+fn day18_overflow() {
+    let puzzle = advent_of_code_2020::puzzle(18).unwrap();
+
+    assert!(puzzle.solve("1 + 2\n99999999999 * 99999999999\n").is_err());
+}
+
+#[test]
+fn day09_short_input() {
+    let puzzle = advent_of_code_2020::puzzle(9).unwrap();
+
+    assert!(puzzle.solve("").is_err());
+    assert!(puzzle.solve("1\n2\n3\n").is_err());
+}